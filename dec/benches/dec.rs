@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::convert::TryFrom;
 
 use criterion::{criterion_group, criterion_main, Bencher, Criterion};
@@ -84,6 +85,14 @@ pub fn bench_standard_notation_string(d: Decimal128, b: &mut Bencher) {
     )
 }
 
+pub fn bench_write_to(d: Decimal<13>, b: &mut Bencher) {
+    let mut buf = String::new();
+    b.iter(|| {
+        buf.clear();
+        d.write_to(&mut buf).unwrap();
+    })
+}
+
 pub fn bench_print(c: &mut Criterion) {
     let mut rng = thread_rng();
     let mut cx = Context::<Decimal128>::default();
@@ -95,6 +104,12 @@ pub fn bench_print(c: &mut Criterion) {
     c.bench_function("to_standard_notation_string_dec128", |b| {
         bench_standard_notation_string(d128, b)
     });
+    let mut rng = thread_rng();
+    let d: Decimal<13> = i32::into(rng.gen());
+    c.bench_function("to_string_dec13", |b| {
+        b.iter(|| d.to_string());
+    });
+    c.bench_function("write_to_dec13", |b| bench_write_to(d, b));
 }
 
 pub fn bench_try_into_primitive(d: Decimal<13>, b: &mut Bencher) {
@@ -125,5 +140,61 @@ pub fn bench_tryinto_primitive(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_decode, bench_print, bench_tryinto_primitive);
+pub fn bench_equals(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let ds: Vec<Decimal<13>> = (0..64)
+        .map(|_| {
+            let mut d: Decimal<13> = i64::into(rng.gen());
+            d.set_exponent(rng.gen_range(-4, 4));
+            d
+        })
+        .collect();
+    let mut cx = Context::<Decimal<13>>::default();
+    c.bench_function("partial_cmp_eq_dec13", |b| {
+        b.iter(|| {
+            let mut n = 0;
+            for (x, y) in ds.iter().zip(ds.iter().rev()) {
+                n += usize::from(cx.partial_cmp(x, y) == Some(Ordering::Equal));
+            }
+            n
+        })
+    });
+    c.bench_function("equals_dec13", |b| {
+        b.iter(|| {
+            let mut n = 0;
+            for (x, y) in ds.iter().zip(ds.iter().rev()) {
+                n += usize::from(cx.equals(x, y));
+            }
+            n
+        })
+    });
+}
+
+pub fn bench_widen(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let d: Decimal<13> = i64::into(rng.gen());
+    let mut cx = Context::<Decimal<26>>::default();
+    c.bench_function("to_width_dec13_dec26", |b| b.iter(|| cx.to_width(d)));
+    c.bench_function("widen_dec13_dec26", |b| b.iter(|| d.widen::<26>()));
+}
+
+pub fn bench_parse_bytes(c: &mut Criterion) {
+    let s = "-123456789012345678.9012345678901234E-12";
+    let b = s.as_bytes();
+    let mut cx = Context::<Decimal<13>>::default();
+    c.bench_function("parse_dec13", |bench| bench.iter(|| cx.parse(s)));
+    c.bench_function("parse_bytes_dec13", |bench| {
+        bench.iter(|| cx.parse_bytes(b))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_decode,
+    bench_print,
+    bench_tryinto_primitive,
+    bench_equals,
+    bench_widen,
+    bench_parse_bytes
+);
 criterion_main!(benches);
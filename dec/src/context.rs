@@ -13,12 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
-use std::marker::PhantomData;
-use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
 
 use libc::c_uint;
 
+use crate::error::DecimalError;
+
 /// A context for performing decimal operations.
 ///
 /// Contexts serve two purposes:
@@ -40,6 +43,7 @@ use libc::c_uint;
 #[derive(Clone)]
 pub struct Context<D> {
     pub(crate) inner: decnumber_sys::decContext,
+    pub(crate) status_policy: StatusPolicy,
     pub(crate) _phantom: PhantomData<D>,
 }
 
@@ -51,6 +55,7 @@ impl<D> fmt::Debug for Context<D> {
             .field("emax", &self.inner.emax)
             .field("emin", &self.inner.emin)
             .field("rounding", &self.rounding())
+            .field("status_policy", &self.status_policy)
             .field("traps", &self.inner.traps)
             .finish()
     }
@@ -83,6 +88,177 @@ impl<D> Context<D> {
     pub fn clear_status(&mut self) {
         self.inner.status = 0;
     }
+
+    /// Returns the context's status policy.
+    pub fn status_policy(&self) -> StatusPolicy {
+        self.status_policy
+    }
+
+    /// Sets the context's status policy.
+    ///
+    /// The policy is consulted by [`Context::checked_op`].
+    pub fn set_status_policy(&mut self, policy: StatusPolicy) {
+        self.status_policy = policy;
+    }
+
+    /// Runs the operation `f` against this context and handles the conditions
+    /// it raises according to the context's [`StatusPolicy`].
+    ///
+    /// As with any other operation, the raised conditions are also
+    /// accumulated into the context's status.
+    ///
+    /// ```
+    /// use dec::{Context, Decimal64, Status, StatusPolicy};
+    /// let mut cx = Context::<Decimal64>::default();
+    /// let mut conditions = Status::default();
+    /// conditions.set_division_by_zero();
+    /// cx.set_status_policy(StatusPolicy::ReturnErr(conditions));
+    /// let err = cx
+    ///     .checked_op(|cx| cx.div(Decimal64::from(1), Decimal64::ZERO))
+    ///     .unwrap_err();
+    /// assert!(err.status().division_by_zero());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context's policy is [`StatusPolicy::Panic`] and `f`
+    /// raises any of the policy's conditions.
+    pub fn checked_op<R, F>(&mut self, f: F) -> Result<R, DecimalError>
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let (r, raised) = self.isolate_status(f);
+        match self.status_policy {
+            StatusPolicy::Ignore => Ok(r),
+            StatusPolicy::ReturnErr(conditions) => {
+                if (raised & conditions).any() {
+                    Err(DecimalError::new(raised))
+                } else {
+                    Ok(r)
+                }
+            }
+            StatusPolicy::Panic(conditions) => {
+                if (raised & conditions).any() {
+                    panic!(
+                        "decimal operation raised exceptional conditions: {:?}",
+                        raised
+                    );
+                }
+                Ok(r)
+            }
+        }
+    }
+
+    /// Runs `f` against this context and returns its result along with the
+    /// conditions raised while it ran, excluding any that were already set.
+    /// The raised conditions are accumulated into the context's status as
+    /// usual.
+    pub(crate) fn isolate_status<R, F>(&mut self, f: F) -> (R, Status)
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let prev = self.status();
+        self.clear_status();
+        let r = f(self);
+        let raised = self.status();
+        self.set_status(prev | raised);
+        (r, raised)
+    }
+}
+
+/// A builder for a [`Context`].
+///
+/// Each method configures one property of the context. Properties that are
+/// not configured retain their values from the context's default. All of the
+/// configured properties are validated together when the context is built.
+///
+/// ```
+/// use dec::{ContextBuilder, Decimal, Rounding};
+/// let cx = ContextBuilder::<Decimal<12>>::new()
+///     .precision(10)
+///     .rounding(Rounding::Down)
+///     .max_exponent(99)
+///     .min_exponent(-99)
+///     .build()
+///     .unwrap();
+/// assert_eq!(cx.precision(), 10);
+/// assert_eq!(cx.rounding(), Rounding::Down);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContextBuilder<D> {
+    pub(crate) precision: Option<usize>,
+    pub(crate) rounding: Option<Rounding>,
+    pub(crate) max_exponent: Option<isize>,
+    pub(crate) min_exponent: Option<isize>,
+    pub(crate) clamp: Option<bool>,
+    pub(crate) _phantom: PhantomData<D>,
+}
+
+impl<D> Default for ContextBuilder<D> {
+    fn default() -> ContextBuilder<D> {
+        ContextBuilder {
+            precision: None,
+            rounding: None,
+            max_exponent: None,
+            min_exponent: None,
+            clamp: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D> ContextBuilder<D> {
+    /// Constructs a builder that has no properties configured.
+    pub fn new() -> ContextBuilder<D> {
+        ContextBuilder::default()
+    }
+
+    /// Configures the context's precision.
+    pub fn precision(mut self, precision: usize) -> ContextBuilder<D> {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Configures the context's rounding algorithm.
+    pub fn rounding(mut self, rounding: Rounding) -> ContextBuilder<D> {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Configures the context's maximum exponent.
+    pub fn max_exponent(mut self, e: isize) -> ContextBuilder<D> {
+        self.max_exponent = Some(e);
+        self
+    }
+
+    /// Configures the context's minimum exponent.
+    pub fn min_exponent(mut self, e: isize) -> ContextBuilder<D> {
+        self.min_exponent = Some(e);
+        self
+    }
+
+    /// Configures whether the context has exponent clamping enabled.
+    pub fn clamp(mut self, clamp: bool) -> ContextBuilder<D> {
+        self.clamp = Some(clamp);
+        self
+    }
+}
+
+/// Determines how [`Context::checked_op`] handles the conditions raised by an
+/// operation.
+///
+/// The `ReturnErr` and `Panic` policies apply only to the conditions set in
+/// their [`Status`]; operations that raise only other conditions succeed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum StatusPolicy {
+    /// Ignore all raised conditions. The conditions are still accumulated in
+    /// the context's status.
+    #[default]
+    Ignore,
+    /// Return an error if any of the specified conditions are raised.
+    ReturnErr(Status),
+    /// Panic if any of the specified conditions are raised.
+    Panic(Status),
 }
 
 /// Algorithms for rounding decimal numbers.
@@ -183,12 +359,80 @@ impl fmt::Debug for Status {
     }
 }
 
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, name) in self.names().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+// The conditions reported by `Status::flags`, in the same order as the fields
+// of the `Debug` representation.
+const STATUS_FLAGS: [(u32, &str); 13] = [
+    (decnumber_sys::DEC_Conversion_syntax, "conversion_syntax"),
+    (decnumber_sys::DEC_Division_by_zero, "division_by_zero"),
+    (
+        decnumber_sys::DEC_Division_impossible,
+        "division_impossible",
+    ),
+    (decnumber_sys::DEC_Division_undefined, "division_undefined"),
+    (
+        decnumber_sys::DEC_Insufficient_storage,
+        "insufficient_storage",
+    ),
+    (decnumber_sys::DEC_Inexact, "inexact"),
+    (decnumber_sys::DEC_Invalid_context, "invalid_context"),
+    (decnumber_sys::DEC_Invalid_operation, "invalid_operation"),
+    (decnumber_sys::DEC_Overflow, "overflow"),
+    (decnumber_sys::DEC_Clamped, "clamped"),
+    (decnumber_sys::DEC_Rounded, "rounded"),
+    (decnumber_sys::DEC_Subnormal, "subnormal"),
+    (decnumber_sys::DEC_Underflow, "underflow"),
+];
+
 impl Status {
     /// Reports whether any of the condition flags are set.
     pub fn any(&self) -> bool {
         self.inner != 0
     }
 
+    /// Reports whether none of the condition flags are set.
+    ///
+    /// This is the inverse of [`Status::any`].
+    pub fn is_empty(&self) -> bool {
+        !self.any()
+    }
+
+    /// Returns the names of the condition flags that are set.
+    ///
+    /// Each name matches the method that reports the flag, e.g. `"inexact"`
+    /// for [`Status::inexact`]. The [`Display`](fmt::Display) implementation
+    /// prints the same names separated by commas.
+    ///
+    /// ```
+    /// use dec::Status;
+    /// let mut status = Status::default();
+    /// status.set_rounded();
+    /// status.set_inexact();
+    /// assert_eq!(status.flags(), ["inexact", "rounded"]);
+    /// assert_eq!(status.to_string(), "inexact, rounded");
+    /// ```
+    pub fn flags(&self) -> Vec<&'static str> {
+        self.names().collect()
+    }
+
+    fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        STATUS_FLAGS
+            .iter()
+            .filter(move |(flag, _)| self.inner & flag != 0)
+            .map(|(_, name)| *name)
+    }
+
     /// Reports whether the conversion syntax flag is set.
     ///
     /// Operations set this flag when an invalid string is converted to a
@@ -13,31 +13,109 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::Ordering;
-use std::convert::{TryFrom, TryInto};
-use std::ffi::{CStr, CString};
-use std::fmt;
-use std::marker::PhantomData;
-use std::mem::MaybeUninit;
-use std::ops::Neg;
-use std::str::FromStr;
+use alloc::ffi::CString;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::{self, Ordering};
+use core::convert::{TryFrom, TryInto};
+use core::ffi::CStr;
+use core::fmt::{self, Write as _};
+use core::iter;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{AddAssign, DivAssign, MulAssign, Neg, SubAssign};
+use core::str::FromStr;
 
 use libc::c_char;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::context::{Class, Context};
+use crate::context::{Class, Context, ContextBuilder, Rounding, Status, StatusPolicy};
 use crate::decimal128::Decimal128;
 use crate::decimal32::Decimal32;
 use crate::decimal64::Decimal64;
 use crate::error::{
-    InvalidCoefficientError, InvalidExponentError, InvalidPrecisionError, ParseDecimalError,
-    TryFromDecimalError,
+    BufferTooSmallError, DecimalError, InexactError, InvalidCanonicalBytesError,
+    InvalidCoefficientError, InvalidContextError, InvalidExponentError, InvalidLogicalOperandError,
+    InvalidPrecisionError, InvalidRawPartsError, InvalidTaggedBytesError, ParseDecimalError,
+    ParseDecimalErrorKind, TryFromDecimalError, TryFromFloatError,
 };
 
-fn validate_n(n: usize) {
+// The length at or below which `Context::sum_pairwise` stops splitting its
+// input and sums it naively. Recursing all the way down to single elements
+// would not improve accuracy meaningfully but would add call overhead.
+const PAIRWISE_SUM_THRESHOLD: usize = 8;
+
+// The size of the stack buffer into which `Context::parse_bytes` copies its
+// input to add a NUL terminator. Longer inputs are copied to the heap.
+const PARSE_BYTES_STACK_LEN: usize = 64;
+
+// The version of the encoding produced by `Decimal::to_tagged_bytes`, and the
+// length of its header: the version, the digit count, the bits, and the
+// exponent.
+const TAGGED_BYTES_VERSION: u8 = 1;
+const TAGGED_BYTES_HEADER_LEN: usize = 10;
+
+// The version of the encoding produced by `Decimal::to_canonical_bytes`.
+const CANONICAL_BYTES_VERSION: u8 = 1;
+
+// Appends `digits`, which must each be less than ten, to `buf` as packed
+// binary-coded decimal, two digits per byte, most significant first.
+fn pack_bcd(digits: &[u8], buf: &mut Vec<u8>) {
+    for pair in digits.chunks(2) {
+        buf.push(pair[0] << 4 | pair.get(1).copied().unwrap_or(0));
+    }
+}
+
+// Appends `n` to `buf` as an unsigned LEB128 varint.
+fn write_varint(buf: &mut Vec<u8>, mut n: u32) {
+    while n >= 0x80 {
+        buf.push((n & 0x7f) as u8 | 0x80);
+        n >>= 7;
+    }
+    buf.push(n as u8);
+}
+
+// Reads an unsigned LEB128 varint from the start of `bytes`, advancing past
+// it. Returns `None` if the varint is truncated, does not fit in a `u32`, or
+// is not in its shortest form.
+fn read_varint(bytes: &mut &[u8]) -> Option<u32> {
+    let mut n = 0u32;
+    for (i, byte) in bytes.iter().enumerate() {
+        let shift = 7 * u32::try_from(i).ok()?;
+        let value = u32::from(byte & 0x7f);
+        if shift >= 32 || (value << shift) >> shift != value {
+            return None;
+        }
+        n |= value << shift;
+        if byte & 0x80 == 0 {
+            // A final zero byte would be redundant, unless it is the only
+            // byte.
+            if i > 0 && *byte == 0 {
+                return None;
+            }
+            *bytes = &bytes[i + 1..];
+            return Some(n);
+        }
+    }
+    None
+}
+
+// Reports whether `exponent` is valid for a finite number with `digits`
+// digits. libdecnumber requires that the exponent be at least -1999999997 and
+// that the adjusted exponent be at most 999999999.
+pub(crate) fn exponent_in_range(digits: u32, exponent: i32) -> bool {
+    let adjusted = i64::from(exponent) + i64::from(digits) - 1;
+    adjusted <= 999_999_999 && i64::from(exponent) >= -1_999_999_997
+}
+
+const MIN_N: usize = 12;
+const MAX_N: usize = 999_999_999;
+
+const fn validate_n(n: usize) {
     // TODO(benesch): check this at compile time, when that becomes possible.
-    if n < 12 || n > 999_999_999 {
+    if n < MIN_N || n > MAX_N {
         panic!("Decimal<N>:: N is not in the range [12, 999999999]");
     }
 }
@@ -54,23 +132,156 @@ fn validate_n(n: usize) {
 /// the stack size implies a smaller maximum for `N`. Due to limitations with
 /// constant generics it is not yet possible to enforce these restrictions
 /// at compile time, so they are checked at runtime.
+///
+/// # Serialization
+///
+/// When the `serde` feature is enabled, decimals implement `Serialize` and
+/// `Deserialize`. For human-readable formats, like JSON, a decimal is
+/// serialized as its string form, which does not depend on `N`. For other
+/// formats, a decimal is represented by its raw internal fields, which is
+/// more compact but only portable between decimals with the same `N`.
+///
+/// Earlier versions of this crate serialized decimals as their raw internal
+/// fields in every format. To read data written by those versions,
+/// deserialization from a human-readable format also accepts the raw struct
+/// form, as well as plain numbers, like the JSON number `1.5`. Numbers that
+/// the format parses as floats are converted as if by [`TryFrom<f64>`], so
+/// they are subject to the format's float precision.
 #[repr(C)]
 #[derive(Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Decimal<const N: usize> {
     pub(crate) digits: u32,
     pub(crate) exponent: i32,
     pub(crate) bits: u8,
+    pub(crate) lsu: [u16; N],
+}
+
+/// An opaque key that identifies the numeric value of a [`Decimal`].
+///
+/// Keys are produced by [`Decimal::value_key`]. Two keys are equal if and
+/// only if the decimals that produced them have the same numeric value, so
+/// keys are suitable for grouping or deduplicating decimals in a `HashSet` or
+/// `HashMap`, where decimals that compare equal but have different
+/// representations, like `1.0` and `1.00`, must be treated alike. Keys do not
+/// depend on `N`, so decimals of different widths with the same value have
+/// equal keys.
+///
+/// Keys are not ordered by the values they represent.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ValueKey(Vec<u8>);
+
+/// The representation of a [`Decimal`] in formats that are not human
+/// readable.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Decimal")]
+struct RawDecimal<const N: usize> {
+    digits: u32,
+    exponent: i32,
+    bits: u8,
     /// Must provide custom serde implementation for array defined with const
     /// generic until something happens with
     /// https://github.com/serde-rs/serde/issues/1272
-    #[cfg_attr(feature = "serde", serde(with = "lsu_serde"))]
-    pub(crate) lsu: [u16; N],
+    #[serde(with = "lsu_serde")]
+    lsu: [u16; N],
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> Serialize for Decimal<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            RawDecimal {
+                digits: self.digits,
+                exponent: self.exponent,
+                bits: self.bits,
+                lsu: self.lsu,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> Deserialize<'de> for Decimal<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Decimal<N>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DecimalVisitor)
+        } else {
+            let raw = RawDecimal::<N>::deserialize(deserializer)?;
+            Decimal::from_raw_parts(raw.digits, raw.exponent, raw.bits, raw.lsu)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct DecimalVisitor<const N: usize>;
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::de::Visitor<'de> for DecimalVisitor<N> {
+    type Value = Decimal<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a decimal number with at most {} digits", N * 3)
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Decimal<N>, E>
+    where
+        E: serde::de::Error,
+    {
+        // Refuse to silently round numbers that have more digits than
+        // `Decimal<N>` can hold.
+        let mut cx = Context::<Decimal<N>>::default();
+        match cx.parse(s) {
+            Ok(d) if !cx.status().inexact() => Ok(d),
+            _ => Err(E::invalid_value(serde::de::Unexpected::Str(s), &self)),
+        }
+    }
+
+    fn visit_i64<E>(self, n: i64) -> Result<Decimal<N>, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Decimal::from(n))
+    }
+
+    fn visit_u64<E>(self, n: u64) -> Result<Decimal<N>, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Decimal::from(n))
+    }
+
+    fn visit_f64<E>(self, f: f64) -> Result<Decimal<N>, E>
+    where
+        E: serde::de::Error,
+    {
+        Decimal::try_from(f).map_err(|_| E::invalid_value(serde::de::Unexpected::Float(f), &self))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Decimal<N>, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let raw = RawDecimal::<N>::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+        Decimal::from_raw_parts(raw.digits, raw.exponent, raw.bits, raw.lsu)
+            .map_err(serde::de::Error::custom)
+    }
 }
 
 #[cfg(feature = "serde")]
 mod lsu_serde {
-    use std::convert::TryInto;
+    use alloc::format;
+    use alloc::vec::Vec;
+    use core::convert::TryInto;
 
     use serde::de::{Error, Unexpected};
     use serde::ser::SerializeSeq;
@@ -105,6 +316,42 @@ mod lsu_serde {
 }
 
 impl<const N: usize> Decimal<N> {
+    /// The smallest permissible value of `N`.
+    pub const MIN_N: usize = MIN_N;
+
+    /// The largest permissible value of `N`.
+    pub const MAX_N: usize = MAX_N;
+
+    /// The maximum number of digits that this type can store, `N * 3`.
+    ///
+    /// This is also the largest precision accepted by
+    /// [`Context::<Decimal<N>>::set_precision`].
+    pub const MAX_PRECISION: usize = N * decnumber_sys::DECDPUN;
+
+    /// The value that represents zero.
+    ///
+    /// Like the other associated constants, this is usable in `const`
+    /// contexts, such as the initializer of a `static`.
+    pub const ZERO: Decimal<N> = Decimal::special(0);
+
+    /// The value that represents positive infinity.
+    pub const INFINITY: Decimal<N> = Decimal::special(decnumber_sys::DECINF);
+
+    /// The value that represents a non-signaling NaN.
+    pub const NAN: Decimal<N> = Decimal::special(decnumber_sys::DECNAN);
+
+    // Constructs a decimal with a zero coefficient and exponent and the
+    // specified flags.
+    const fn special(bits: u8) -> Decimal<N> {
+        validate_n(N);
+        Decimal {
+            digits: 1,
+            exponent: 0,
+            bits,
+            lsu: [0; N],
+        }
+    }
+
     pub(crate) fn as_ptr(&self) -> *const decnumber_sys::decNumber {
         self as *const Decimal<N> as *const decnumber_sys::decNumber
     }
@@ -115,22 +362,24 @@ impl<const N: usize> Decimal<N> {
 
     /// Constructs a decimal number with `N / 3` digits of precision
     /// representing the number 0.
-    pub fn zero() -> Decimal<N> {
-        Decimal::default()
+    ///
+    /// This is equivalent to [`Decimal::ZERO`].
+    pub const fn zero() -> Decimal<N> {
+        Decimal::ZERO
     }
 
     /// Constructs a decimal number representing positive infinity.
-    pub fn infinity() -> Decimal<N> {
-        let mut d = Decimal::default();
-        d.bits = decnumber_sys::DECINF;
-        d
+    ///
+    /// This is equivalent to [`Decimal::INFINITY`].
+    pub const fn infinity() -> Decimal<N> {
+        Decimal::INFINITY
     }
 
     /// Constructs a decimal number representing a non-signaling NaN.
-    pub fn nan() -> Decimal<N> {
-        let mut d = Decimal::default();
-        d.bits = decnumber_sys::DECNAN;
-        d
+    ///
+    /// This is equivalent to [`Decimal::NAN`].
+    pub const fn nan() -> Decimal<N> {
+        Decimal::NAN
     }
 
     // Constructs a decimal number equal to 2^32. We use this value internally
@@ -162,6 +411,80 @@ impl<const N: usize> Decimal<N> {
         buf
     }
 
+    /// Writes the individual digits of the coefficient into `buf` in the
+    /// format of [`coefficient_digits`](Decimal::coefficient_digits),
+    /// returning the number of digits written.
+    ///
+    /// Unlike `coefficient_digits`, this method does not allocate, so a single
+    /// buffer can be reused across many calls. Returns an error if `buf` is
+    /// shorter than [`digits`](Decimal::digits); a buffer of `N * 3` bytes is
+    /// always sufficient. Bytes in `buf` beyond the digits written are left
+    /// unchanged.
+    pub fn coefficient_digits_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        let digits = usize::try_from(self.digits()).unwrap();
+        if buf.len() < digits {
+            return Err(BufferTooSmallError);
+        }
+        unsafe {
+            decnumber_sys::decNumberGetBCD(self.as_ptr(), buf.as_mut_ptr());
+        };
+        Ok(digits)
+    }
+
+    /// Returns the `i`th digit of the coefficient, counting from the most
+    /// significant digit at index 0.
+    ///
+    /// Returns `None` if `i` is not less than [`digits`](Decimal::digits).
+    /// This agrees with the corresponding element of
+    /// [`coefficient_digits`](Decimal::coefficient_digits), but reads the
+    /// digit directly from the coefficient without allocating.
+    pub fn digit(&self, i: u32) -> Option<u8> {
+        if i < self.digits {
+            Some(self.lsd(self.digits - 1 - i))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of trailing zeros in the coefficient, i.e., the
+    /// number of least significant digits of the coefficient that are zero.
+    ///
+    /// For example, `1.500` and `1500` both have two trailing zeros, and
+    /// `1.5E+3` has none. Reducing a number, as by
+    /// [`normalized`](Decimal::normalized), removes exactly this many digits.
+    /// Zeros, infinities, and NaNs have no trailing zeros.
+    ///
+    /// The count is computed from the coefficient's units directly, without
+    /// reducing the number.
+    pub fn trailing_zeros(&self) -> u32 {
+        if self.is_special() || self.is_zero() {
+            return 0;
+        }
+        let mut zeros = 0;
+        for unit in self.coefficient_units() {
+            if *unit == 0 {
+                zeros += decnumber_sys::DECDPUN as u32;
+            } else {
+                let mut unit = *unit;
+                while unit % 10 == 0 {
+                    zeros += 1;
+                    unit /= 10;
+                }
+                break;
+            }
+        }
+        zeros
+    }
+
+    // Returns the `i`th least significant digit of the coefficient. `i` must
+    // be less than `self.digits()`.
+    fn lsd(&self, i: u32) -> u8 {
+        let i = usize::try_from(i).unwrap();
+        let unit = self.lsu[i / decnumber_sys::DECDPUN];
+        let digit = unit / 10u16.pow((i % decnumber_sys::DECDPUN) as u32) % 10;
+        u8::try_from(digit).unwrap()
+    }
+
     /// Returns the digits of the coefficient in [`decNumberUnit`][dnu] format,
     /// which is a vector of `u16`, with element number representing
     /// [`decnumber_sys::DECDPUN`] digits of the coefficient.
@@ -210,6 +533,52 @@ impl<const N: usize> Decimal<N> {
         self.exponent = exponent;
     }
 
+    /// Computes the scale of the number, i.e., the number of digits after the
+    /// decimal point.
+    ///
+    /// The scale is the negation of the exponent when the exponent is negative
+    /// and zero otherwise, so `1.50` has a scale of 2 and `1E+3` has a scale of
+    /// 0. Special values have a scale of 0.
+    pub fn scale(&self) -> i32 {
+        if self.is_special() || self.exponent >= 0 {
+            0
+        } else {
+            -self.exponent
+        }
+    }
+
+    /// Computes the precision and scale of the number, as used by SQL's
+    /// `NUMERIC(p, s)` type.
+    ///
+    /// The precision is the total number of digits needed to write the number
+    /// in plain notation, including any zeros implied by a positive exponent
+    /// and any leading zeros after the decimal point. For example, `1.50` is
+    /// `(3, 2)`, `1E+3` is `(4, 0)`, and `0.001` is `(3, 3)`.
+    ///
+    /// Special values return `(0, 0)`, which no finite number produces.
+    pub fn precision_and_scale(&self) -> (u64, i32) {
+        if self.is_special() {
+            return (0, 0);
+        }
+        let scale = self.scale();
+        let integral = u64::from(self.digits) + u64::try_from(self.exponent.max(0)).unwrap();
+        let precision = integral.max(u64::try_from(scale).unwrap());
+        (precision, scale)
+    }
+
+    /// Reports whether the encoding of the number is canonical.
+    ///
+    /// Unlike the densely packed encodings of [`Decimal64`] and
+    /// [`Decimal128`], the coefficient of a `Decimal` is stored in binary
+    /// units that have exactly one representation per value, and
+    /// [`Decimal::from_raw_parts`] rejects any parts that would not be produced
+    /// by libdecnumber itself. This method therefore always returns `true`; it
+    /// exists for parity with [`Decimal64::is_canonical`] and
+    /// [`Decimal128::is_canonical`].
+    pub fn is_canonical(&self) -> bool {
+        true
+    }
+
     /// Reports whether the number is finite.
     ///
     /// A finite number is one that is neither infinite nor a NaN.
@@ -222,6 +591,48 @@ impl<const N: usize> Decimal<N> {
         (self.bits & decnumber_sys::DECINF) != 0
     }
 
+    /// Reports whether the number is an integer.
+    ///
+    /// An integer is a finite number with no nonzero digits after the decimal
+    /// point. Unlike [`Decimal64::is_integer`], this does not require the
+    /// exponent to be zero; for example, `1.00` and `1E+2` are both integers.
+    pub fn is_integer(&self) -> bool {
+        if !self.is_finite() {
+            return false;
+        }
+        if self.exponent >= 0 || self.is_zero() {
+            return true;
+        }
+        let fractional_digits = self.exponent.unsigned_abs();
+        fractional_digits < self.digits && (0..fractional_digits).all(|i| self.lsd(i) == 0)
+    }
+
+    /// Reports whether the number is a valid operand for logical operations,
+    /// like [`Context::<Decimal<N>>::and`].
+    ///
+    /// A valid logical operand is a finite, nonnegative integer with an
+    /// exponent of zero whose digits are all either zero or one.
+    pub fn is_logical(&self) -> bool {
+        self.check_logical().is_ok()
+    }
+
+    // Returns an error describing why the number is not a valid logical
+    // operand, if it is not.
+    fn check_logical(&self) -> Result<(), InvalidLogicalOperandError> {
+        let err = |reason| Err(InvalidLogicalOperandError::new(reason));
+        if self.is_special() {
+            err("special value")
+        } else if self.is_negative() {
+            err("negative sign")
+        } else if self.exponent != 0 {
+            err("nonzero exponent")
+        } else if (0..self.digits).any(|i| self.lsd(i) > 1) {
+            err("digit other than 0 or 1")
+        } else {
+            Ok(())
+        }
+    }
+
     /// Reports whether the number is a NaN.
     pub fn is_nan(&self) -> bool {
         (self.bits & (decnumber_sys::DECNAN | decnumber_sys::DECSNAN)) != 0
@@ -236,6 +647,22 @@ impl<const N: usize> Decimal<N> {
         (self.bits & decnumber_sys::DECNEG) != 0
     }
 
+    /// Reports whether the number is an integral power of ten, like `1`,
+    /// `10`, or `0.01`.
+    ///
+    /// A power of ten is a finite, positive number whose coefficient, once
+    /// reduced as by [`Decimal::normalized`], is exactly `1`. The exponent is
+    /// irrelevant, so `100`, `1E+2`, and `100.00` are all powers of ten.
+    /// Negative numbers are never powers of ten, so `-10` is not; use
+    /// [`Decimal::abs`] to test the magnitude alone. Zeros are not powers of
+    /// ten.
+    pub fn is_power_of_ten(&self) -> bool {
+        self.is_finite()
+            && !self.is_negative()
+            && self.lsd(self.digits - 1) == 1
+            && (0..self.digits - 1).all(|i| self.lsd(i) == 0)
+    }
+
     /// Reports whether the number is a quiet NaN.
     pub fn is_quiet_nan(&self) -> bool {
         (self.bits & decnumber_sys::DECNAN) != 0
@@ -259,6 +686,59 @@ impl<const N: usize> Decimal<N> {
         self.is_finite() && self.lsu[0] == 0 && self.digits == 1
     }
 
+    /// Reports whether the number is positive zero.
+    pub fn is_positive_zero(&self) -> bool {
+        self.is_zero() && !self.is_negative()
+    }
+
+    /// Reports whether the number is negative zero.
+    ///
+    /// Note that [`Decimal::is_negative`] also returns true for negative zero,
+    /// while `is_zero` returns true for zeros of either sign.
+    pub fn is_negative_zero(&self) -> bool {
+        self.is_zero() && self.is_negative()
+    }
+
+    /// Classifies the number without consulting a context.
+    ///
+    /// Whether a number is subnormal depends on the minimum exponent in use.
+    /// This method uses the minimum exponent of the default context,
+    /// [`MIN_EMIN`](Context::<Decimal<N>>::MIN_EMIN), so it agrees with [`Context::<Decimal<N>>::class`] on a
+    /// default context. Use that method to classify numbers against a
+    /// narrower exponent range.
+    pub fn classify(&self) -> Class {
+        let negative = self.is_negative();
+        if self.is_signaling_nan() {
+            Class::SignalingNan
+        } else if self.is_nan() {
+            Class::QuietNan
+        } else if self.is_infinite() {
+            if negative {
+                Class::NegInfinity
+            } else {
+                Class::PosInfinity
+            }
+        } else if self.is_zero() {
+            if negative {
+                Class::NegZero
+            } else {
+                Class::PosZero
+            }
+        } else if i64::from(self.exponent) + i64::from(self.digits) - 1
+            < i64::try_from(Context::<Decimal<N>>::MIN_EMIN).unwrap()
+        {
+            if negative {
+                Class::NegSubnormal
+            } else {
+                Class::PosSubnormal
+            }
+        } else if negative {
+            Class::NegNormal
+        } else {
+            Class::PosNormal
+        }
+    }
+
     /// Reports whether the quantum of the number matches the quantum of
     /// `rhs`.
     ///
@@ -282,6 +762,333 @@ impl<const N: usize> Decimal<N> {
         }
     }
 
+    /// Returns the absolute value of this decimal.
+    ///
+    /// Unlike [`Context::<Decimal<N>>::abs`], this never rounds and raises no
+    /// conditions: the result is a copy of this decimal with the sign cleared.
+    /// Special values are copied in the same way, so `-NaN` produces `NaN`
+    /// and `-sNaN` produces `sNaN`.
+    pub fn abs(&self) -> Decimal<N> {
+        let mut n = *self;
+        unsafe {
+            decnumber_sys::decNumberCopyAbs(n.as_mut_ptr(), n.as_ptr());
+        }
+        n
+    }
+
+    /// Returns the absolute difference between this decimal and `other`,
+    /// `|self - other|`, as with [`i64::abs_diff`].
+    ///
+    /// The difference is computed by [`Context::<Decimal<N>>::sub`] in the
+    /// default context, and so is rounded to `N * 3` digits. If either operand
+    /// is a NaN, the result is a quiet NaN, as it is for the difference of two
+    /// infinities of the same sign.
+    pub fn abs_diff(&self, other: &Decimal<N>) -> Decimal<N> {
+        let mut n = *self;
+        Context::<Decimal<N>>::default().sub(&mut n, other);
+        n.abs()
+    }
+
+    /// Returns the larger of this decimal and `other`.
+    ///
+    /// This is a convenience wrapper around
+    /// [`Context::<Decimal<N>>::max`] using the default context, and so has
+    /// the same NaN semantics: if exactly one operand is a quiet NaN, the
+    /// other operand is returned; if either operand is a signaling NaN, the
+    /// result is a NaN. Use the context method directly to observe
+    /// exceptional conditions.
+    pub fn max(mut self, other: Decimal<N>) -> Decimal<N> {
+        Context::<Decimal<N>>::default().max(&mut self, &other);
+        self
+    }
+
+    /// Returns the smaller of this decimal and `other`.
+    ///
+    /// This is a convenience wrapper around
+    /// [`Context::<Decimal<N>>::min`] using the default context, and so has
+    /// the same NaN semantics as [`Decimal::max`].
+    pub fn min(mut self, other: Decimal<N>) -> Decimal<N> {
+        Context::<Decimal<N>>::default().min(&mut self, &other);
+        self
+    }
+
+    /// Returns this decimal in its reduced form, as produced by
+    /// [`Context::<Decimal<N>>::reduce`] in the default context.
+    ///
+    /// Trailing zeros are removed from the coefficient, so `1.200` and
+    /// `12.0E-1` both produce `1.2`, and all zeros produce `0` of the same
+    /// sign. The result may have a different exponent than this decimal, but
+    /// always has the same numeric value, so nonzero numbers that compare
+    /// equal have identical reduced forms. Zeros are the exception: `0` and
+    /// `-0` compare equal, but keep their signs when reduced. Use
+    /// [`Decimal::value_key`] for a key that treats all equal values alike.
+    pub fn normalized(&self) -> Decimal<N> {
+        let mut n = *self;
+        Context::<Decimal<N>>::default().reduce(&mut n);
+        n
+    }
+
+    /// Returns a key that identifies the numeric value of this decimal.
+    ///
+    /// Decimals that compare equal produce equal keys, even if their
+    /// representations differ: `1.0` and `1.00` produce the same key, as do
+    /// `0` and `-0`. The key is computed from the decimal's reduced form, as
+    /// produced by [`Decimal::normalized`], with the sign of zeros discarded.
+    ///
+    /// Unlike equality, which never holds for NaNs, all NaNs produce the same
+    /// key, regardless of their sign, payload, or whether they are signaling,
+    /// so that they are grouped together. Infinities produce one key per sign.
+    pub fn value_key(&self) -> ValueKey {
+        let d = if self.is_nan() {
+            Decimal::<N>::nan()
+        } else if self.is_zero() {
+            Decimal::<N>::zero()
+        } else {
+            self.normalized()
+        };
+        ValueKey(d.to_canonical_bytes())
+    }
+
+    /// Returns the reciprocal of this decimal, `1/x`, as computed by
+    /// [`Context::<Decimal<N>>::div`] in the default context.
+    ///
+    /// The result is rounded to the default context's precision of `N * 3`
+    /// digits. As with [`f64::recip`], the reciprocal of a zero is an infinity
+    /// of the same sign, the reciprocal of an infinity is a zero of the same
+    /// sign, and the reciprocal of a NaN is a NaN.
+    pub fn recip(&self) -> Decimal<N> {
+        let mut n = Decimal::<N>::from(1);
+        Context::<Decimal<N>>::default().div(&mut n, self);
+        n
+    }
+
+    /// Computes `self * a + b` with only one rounding, as with
+    /// [`f64::mul_add`].
+    ///
+    /// The computation is delegated to [`Context::<Decimal<N>>::fma`], using a
+    /// context with a precision of `N * 3` digits. libdecnumber only supports
+    /// fused multiply-add in contexts whose exponent range is within
+    /// `[-999,999, 999,999]`, so the context has that range, and results
+    /// beyond it overflow or underflow. Conditions raised by the operation are
+    /// discarded; call `fma` on a context directly to observe them.
+    pub fn mul_add(&self, a: &Decimal<N>, b: &Decimal<N>) -> Decimal<N> {
+        let mut cx = ContextBuilder::<Decimal<N>>::new()
+            .precision(Decimal::<N>::MAX_PRECISION)
+            .max_exponent(999_999)
+            .min_exponent(-999_999)
+            .build()
+            .expect("fused multiply-add context is valid");
+        let mut r = *self;
+        cx.fma(&mut r, a, b);
+        r
+    }
+
+    /// Raises this decimal to the integral power `exp`, returning `None` if
+    /// the result cannot be represented exactly.
+    ///
+    /// The power is computed with [`Context::<Decimal<N>>::powi`] in the
+    /// default context. Rather than rounding, overflowing to infinity, or
+    /// underflowing to zero, this method returns `None` whenever the exact
+    /// result does not fit within the default context's precision of `N * 3`
+    /// digits and its exponent range. This includes negative powers whose
+    /// reciprocal does not terminate, like `3^-1`, and negative powers of
+    /// zero. NaNs also produce `None`.
+    ///
+    /// As with `powi`, any number other than a NaN raised to the power of
+    /// zero, including zero itself, produces `Some(1)`.
+    pub fn checked_pow_i32(&self, exp: i32) -> Option<Decimal<N>> {
+        let mut cx = Context::<Decimal<N>>::default();
+        let mut r = *self;
+        cx.powi(&mut r, exp);
+        let status = cx.status();
+        if r.is_nan() || status.inexact() || status.division_by_zero() {
+            None
+        } else {
+            Some(r)
+        }
+    }
+
+    /// Returns a number that represents the sign of this decimal.
+    ///
+    /// The result is `1` if the number is positive, including positive
+    /// infinity, and `-1` if the number is negative, including negative
+    /// infinity. Zeros produce a zero of the same sign, so `-0` produces `-0`;
+    /// note that this differs from [`f64::signum`], which maps zeros to `1` or
+    /// `-1`. NaNs produce a quiet NaN.
+    pub fn signum(&self) -> Decimal<N> {
+        if self.is_nan() {
+            Decimal::nan()
+        } else if self.is_zero() {
+            let mut d = Decimal::zero();
+            d.bits = self.bits & decnumber_sys::DECNEG;
+            d
+        } else if self.is_negative() {
+            Decimal::from(-1)
+        } else {
+            Decimal::from(1)
+        }
+    }
+
+    /// Returns the magnitude of one unit in the last place of this decimal,
+    /// i.e., `10^exponent`.
+    ///
+    /// The result is always positive and has a coefficient of `1` and the
+    /// same exponent as this decimal, so `1.230` produces `0.001` and `-5E+3`
+    /// produces `1E+3`. Special values, including infinities, produce a quiet
+    /// NaN.
+    pub fn ulp(&self) -> Decimal<N> {
+        if self.is_special() {
+            return Decimal::nan();
+        }
+        let mut d = Decimal::zero();
+        d.lsu[0] = 1;
+        d.exponent = self.exponent;
+        d
+    }
+
+    /// Writes the string representation of this decimal to `w`.
+    ///
+    /// The output is identical to that of the [`Display`](fmt::Display)
+    /// implementation, but the string is formatted in a buffer on the stack
+    /// rather than in a heap allocation.
+    pub fn write_to<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        self.write_with(w, false)
+    }
+
+    fn write_with<W>(&self, w: &mut W, engineering: bool) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        self.with_str(engineering, |s| w.write_str(s))
+    }
+
+    // Calls `f` with the string form of this decimal, formatted into a
+    // buffer on the stack.
+    fn with_str<F, R>(&self, engineering: bool, f: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        // String conversion may need up to `self.digits + 14` characters, per
+        // the libdecnumber documentation. Array lengths cannot yet be computed
+        // from const parameters, so the buffer is assembled from `N` chunks of
+        // `DECDPUN` bytes plus the 14 bytes of overhead.
+        #[repr(C)]
+        struct Buf<const N: usize> {
+            digits: [[u8; decnumber_sys::DECDPUN]; N],
+            overhead: [u8; 14],
+        }
+        let mut buf = MaybeUninit::<Buf<N>>::uninit();
+        let c_str = unsafe {
+            let ptr = buf.as_mut_ptr() as *mut c_char;
+            if engineering {
+                decnumber_sys::decNumberToEngString(self.as_ptr(), ptr);
+            } else {
+                decnumber_sys::decNumberToString(self.as_ptr(), ptr);
+            }
+            CStr::from_ptr(ptr)
+        };
+        f(c_str
+            .to_str()
+            .expect("decNumberToString yields valid UTF-8"))
+    }
+
+    /// Converts this decimal to an `f64` suitable for use as a sort key.
+    ///
+    /// The conversion is monotonic: if this decimal orders before `other`
+    /// according to [`total_cmp`](Context::<Decimal<N>>::total_cmp), then the
+    /// key for this decimal is less than or equal to the key for `other`. The
+    /// keys are not exact, however, so distinct decimals that are closer
+    /// together than `f64`'s resolution may map to the same key, as do `-0`
+    /// and `0`. Finite values beyond `f64`'s range map to the infinity of the
+    /// same sign, as do the infinities themselves.
+    ///
+    /// NaNs map to [`f64::NAN`], which does not participate in `f64`'s
+    /// ordering; callers that need to order NaNs must handle them separately.
+    ///
+    /// The conversion formats this decimal into a buffer on the stack and
+    /// does not allocate.
+    pub fn to_sort_key_f64(&self) -> f64 {
+        if self.is_nan() {
+            f64::NAN
+        } else if self.is_infinite() {
+            if self.is_negative() {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }
+        } else {
+            // Rust's float parsing is correctly rounded, and therefore
+            // monotonic.
+            self.with_str(false, |s| {
+                s.parse()
+                    .expect("finite decimal strings are valid f64 literals")
+            })
+        }
+    }
+
+    /// Converts this decimal to an `i32`, saturating at the bounds of the
+    /// type.
+    ///
+    /// Any fractional part is truncated toward zero. Values, including
+    /// infinities, that are greater than `i32::MAX` produce `i32::MAX`, values
+    /// that are less than `i32::MIN` produce `i32::MIN`, and NaNs produce `0`.
+    /// These are the semantics of an `as` cast from `f64` to `i32`.
+    pub fn saturating_to_i32(&self) -> i32 {
+        self.saturating_to(i32::MIN, i32::MAX)
+    }
+
+    /// Converts this decimal to an `i64`, saturating at the bounds of the
+    /// type.
+    ///
+    /// See [`Decimal::saturating_to_i32`] for details.
+    pub fn saturating_to_i64(&self) -> i64 {
+        self.saturating_to(i64::MIN, i64::MAX)
+    }
+
+    /// Converts this decimal to a `u32`, saturating at the bounds of the
+    /// type.
+    ///
+    /// See [`Decimal::saturating_to_i32`] for details. Negative values,
+    /// including those in (-1, 0) that truncate to zero, produce `0`.
+    pub fn saturating_to_u32(&self) -> u32 {
+        self.saturating_to(u32::MIN, u32::MAX)
+    }
+
+    /// Converts this decimal to a `u64`, saturating at the bounds of the
+    /// type.
+    ///
+    /// See [`Decimal::saturating_to_u32`] for details.
+    pub fn saturating_to_u64(&self) -> u64 {
+        self.saturating_to(u64::MIN, u64::MAX)
+    }
+
+    fn saturating_to<T>(&self, min: T, max: T) -> T
+    where
+        T: Copy + Default + Into<Decimal<N>> + TryFrom<Decimal<N>>,
+    {
+        if self.is_nan() {
+            return T::default();
+        }
+        let mut cx = Context::<Decimal<N>>::default();
+        cx.set_rounding(Rounding::Down);
+        let mut d = *self;
+        cx.round_to_integral(&mut d);
+        if d >= max.into() {
+            max
+        } else if d <= min.into() {
+            min
+        } else {
+            cx.quantize(&mut d, &Decimal::ZERO);
+            match T::try_from(d) {
+                Ok(t) => t,
+                Err(_) => unreachable!("in-range integer converts exactly"),
+            }
+        }
+    }
+
     /// Converts this decimal to a 32-bit decimal float.
     ///
     /// The result may be inexact. Use [`Context::<Decimal32>::from_decimal`]
@@ -290,6 +1097,23 @@ impl<const N: usize> Decimal<N> {
         Context::<Decimal32>::default().from_decimal(self)
     }
 
+    /// Converts this decimal to a 32-bit decimal float, returning an error if
+    /// the conversion would be inexact.
+    ///
+    /// Unlike [`Decimal::to_decimal32`], which rounds values that have too
+    /// many digits and saturates values that are too large, this method
+    /// guarantees that the result has exactly the same value as this decimal.
+    pub fn to_decimal32_exact(&self) -> Result<Decimal32, InexactError> {
+        let mut cx = Context::<Decimal32>::default();
+        let d = cx.from_decimal(self);
+        let status = cx.status();
+        if status.inexact() || status.overflow() {
+            Err(InexactError)
+        } else {
+            Ok(d)
+        }
+    }
+
     /// Converts this decimal to a 64-bit decimal float.
     ///
     /// The result may be inexact. Use [`Context::<Decimal64>::from_decimal`]
@@ -298,6 +1122,23 @@ impl<const N: usize> Decimal<N> {
         Context::<Decimal64>::default().from_decimal(self)
     }
 
+    /// Converts this decimal to a 64-bit decimal float, returning an error if
+    /// the conversion would be inexact.
+    ///
+    /// Unlike [`Decimal::to_decimal64`], which rounds values that have too
+    /// many digits and saturates values that are too large, this method
+    /// guarantees that the result has exactly the same value as this decimal.
+    pub fn to_decimal64_exact(&self) -> Result<Decimal64, InexactError> {
+        let mut cx = Context::<Decimal64>::default();
+        let d = cx.from_decimal(self);
+        let status = cx.status();
+        if status.inexact() || status.overflow() {
+            Err(InexactError)
+        } else {
+            Ok(d)
+        }
+    }
+
     /// Converts this decimal to a 128-bit decimal float.
     ///
     /// The result may be inexact. Use [`Context::<Decimal128>::from_decimal`]
@@ -306,6 +1147,159 @@ impl<const N: usize> Decimal<N> {
         Context::<Decimal128>::default().from_decimal(self)
     }
 
+    /// Converts this decimal to a 128-bit decimal float, returning an error if
+    /// the conversion would be inexact.
+    ///
+    /// Unlike [`Decimal::to_decimal128`], which rounds values that have too
+    /// many digits and saturates values that are too large, this method
+    /// guarantees that the result has exactly the same value as this decimal.
+    pub fn to_decimal128_exact(&self) -> Result<Decimal128, InexactError> {
+        let mut cx = Context::<Decimal128>::default();
+        let d = cx.from_decimal(self);
+        let status = cx.status();
+        if status.inexact() || status.overflow() {
+            Err(InexactError)
+        } else {
+            Ok(d)
+        }
+    }
+
+    /// Converts this decimal to an `i32`, returning an error if the conversion
+    /// is not possible.
+    ///
+    /// The conversion is performed directly by libdecnumber and is exact: it
+    /// succeeds only if this decimal is a finite integer with an exponent of
+    /// zero that lies within the range of `i32`. Values with other exponents,
+    /// like `1.0` or `1E+1`, produce an error even though their values are
+    /// integers; use [`Context::<Decimal<N>>::quantize`] to set the exponent
+    /// to zero first. See also [`Context::<Decimal<N>>::try_into_i32`].
+    pub fn to_i32(&self) -> Result<i32, TryFromDecimalError> {
+        Context::<Decimal<N>>::default().try_into_i32(*self)
+    }
+
+    /// Converts this decimal to a `u32`, returning an error if the conversion
+    /// is not possible.
+    ///
+    /// The same restrictions apply as for [`Decimal::to_i32`]. Note that
+    /// negative zero converts successfully to `0`.
+    pub fn to_u32(&self) -> Result<u32, TryFromDecimalError> {
+        Context::<Decimal<N>>::default().try_into_u32(*self)
+    }
+
+    /// Converts this decimal to an `i128` that represents its value scaled by
+    /// `10^scale`, returning an error if the conversion is not possible.
+    ///
+    /// The result is the coefficient of this decimal once it is rescaled to
+    /// have exactly `scale` digits after the decimal point, as by
+    /// [`Context::<Decimal<N>>::quantize_to_places`]. For example, `1.5` at a
+    /// scale of 2 produces `150`, and `1500` at a scale of -2 produces `15`.
+    ///
+    /// The conversion is exact. An error is returned if this decimal has
+    /// nonzero digits beyond `scale` digits after the decimal point, if the
+    /// rescaled coefficient does not fit in an `i128` or has more than `N * 3`
+    /// digits, or if this decimal is an infinity or NaN.
+    pub fn to_i128_scaled(&self, scale: i32) -> Result<i128, TryFromDecimalError> {
+        if self.is_special() {
+            return Err(TryFromDecimalError);
+        }
+        let mut cx = Context::<Decimal<N>>::default();
+        let mut d = *self;
+        cx.quantize_to_places(&mut d, scale);
+        if cx.status().inexact() || cx.status().invalid_operation() {
+            return Err(TryFromDecimalError);
+        }
+        d.coefficient::<i128>().map_err(|_| TryFromDecimalError)
+    }
+
+    /// Converts this decimal to a decimal of width `M`, returning an error if
+    /// the conversion would not preserve its value.
+    ///
+    /// Narrowing conversions succeed if the value fits within the precision
+    /// of `Decimal<M>`, and widening conversions always succeed. On failure,
+    /// the error carries the conditions raised by the conversion, like inexact
+    /// or overflow. Signaling NaNs cannot be converted and produce an error
+    /// with the invalid operation flag set.
+    ///
+    /// Use [`Context::<Decimal<M>>::to_width`] to round rather than fail. This
+    /// conversion is not available as a `TryFrom` implementation, as it would
+    /// conflict with the standard library's blanket implementation when `M`
+    /// equals `N`.
+    pub fn try_to_width<const M: usize>(&self) -> Result<Decimal<M>, DecimalError> {
+        let mut cx = Context::<Decimal<M>>::default();
+        let mut d = cx.to_width(*self);
+        let status = cx.status();
+        if status.inexact() || status.invalid_operation() {
+            return Err(DecimalError::new(status));
+        }
+        // Conversion adds the value to zero, which discards the sign of a
+        // negative zero.
+        if d.is_zero() {
+            d.bits |= self.bits & decnumber_sys::DECNEG;
+        }
+        Ok(d)
+    }
+
+    /// Converts this decimal to a decimal of width `M`, which must be at least
+    /// `N`.
+    ///
+    /// A `Decimal<M>` can represent every `Decimal<N>` exactly when `M >= N`,
+    /// so this conversion copies the decimal's parts rather than performing
+    /// any arithmetic. Unlike [`Context::<Decimal<M>>::to_width`], it never
+    /// rounds and preserves signaling NaNs and the sign of negative zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is less than `N`. Use [`Decimal::try_to_width`] or
+    /// `to_width` to narrow a decimal.
+    pub fn widen<const M: usize>(&self) -> Decimal<M> {
+        validate_n(M);
+        assert!(
+            M >= N,
+            "cannot widen Decimal<{}> to the narrower Decimal<{}>",
+            N,
+            M
+        );
+        let mut lsu = [0; M];
+        lsu[..N].copy_from_slice(&self.lsu);
+        Decimal {
+            digits: self.digits,
+            exponent: self.exponent,
+            bits: self.bits,
+            lsu,
+        }
+    }
+
+    /// Splits the number into its integer and fractional parts.
+    ///
+    /// The integer part is the number truncated toward zero, and the
+    /// fractional part is the remainder, such that the two parts sum to the
+    /// original number. As with [`f64::fract`], the fractional part has the
+    /// same sign as the original number, even when it is zero.
+    ///
+    /// ```
+    /// use dec::Decimal;
+    /// let d: Decimal<12> = "-12.345".parse().unwrap();
+    /// let (int, fract) = d.split();
+    /// assert_eq!(int.to_string(), "-12");
+    /// assert_eq!(fract.to_string(), "-0.345");
+    /// ```
+    ///
+    /// If the number is infinite, the integer part is the number itself and
+    /// the fractional part is NaN. If the number is a NaN, both parts are
+    /// NaN.
+    pub fn split(&self) -> (Decimal<N>, Decimal<N>) {
+        let mut cx = Context::<Decimal<N>>::default();
+        cx.set_rounding(Rounding::Down);
+        let mut int = *self;
+        cx.round_to_integral(&mut int);
+        let mut fract = *self;
+        cx.sub(&mut fract, &int);
+        if fract.is_zero() && self.is_negative() != fract.is_negative() {
+            cx.neg(&mut fract);
+        }
+        (int, fract)
+    }
+
     /// Returns the raw parts of this decimal.
     ///
     /// The meaning of these parts are unspecified and subject to change.
@@ -313,22 +1307,286 @@ impl<const N: usize> Decimal<N> {
         (self.digits, self.exponent, self.bits, self.lsu)
     }
 
+    /// Constructs a decimal from the raw parts returned by
+    /// [`Decimal::to_raw_parts`].
+    ///
+    /// The parts are checked for internal consistency, so that they cannot
+    /// describe a decimal whose internals would mislead libdecnumber: the
+    /// flags must be known and describe at most one kind of special value, the
+    /// digit count must agree with the coefficient units, infinities must have
+    /// a zero coefficient, and the exponent must be within the range supported
+    /// by the default context. Units in
+    /// `lsu` beyond those that hold the coefficient are ignored.
+    ///
+    /// As with `to_raw_parts`, the meaning of these parts is unspecified and
+    /// subject to change, so they should not be persisted across versions of
+    /// this crate.
+    pub fn from_raw_parts(
+        digits: u32,
+        exponent: i32,
+        bits: u8,
+        mut lsu: [u16; N],
+    ) -> Result<Decimal<N>, InvalidRawPartsError> {
+        let err = |reason| Err(InvalidRawPartsError::new(reason));
+        let specials = bits & decnumber_sys::DECSPECIAL;
+        if bits & !(decnumber_sys::DECNEG | decnumber_sys::DECSPECIAL) != 0
+            || specials.count_ones() > 1
+        {
+            return err("unknown or conflicting flags");
+        }
+        let n_digits = match usize::try_from(digits) {
+            Ok(n_digits) if n_digits != 0 && n_digits <= Decimal::<N>::MAX_PRECISION => n_digits,
+            _ => return err("digit count out of range"),
+        };
+        let units = (n_digits - 1) / decnumber_sys::DECDPUN + 1;
+        if lsu[..units].iter().any(|u| *u > 999) {
+            return err("invalid coefficient unit");
+        }
+        // The most significant unit must contain exactly the number of digits
+        // not accounted for by the lower units, unless the coefficient is a
+        // single zero digit.
+        let msu = lsu[units - 1];
+        let msu_digits = (n_digits - 1) % decnumber_sys::DECDPUN + 1;
+        let lower = if n_digits == 1 {
+            0
+        } else {
+            10u16.pow(msu_digits as u32 - 1)
+        };
+        if msu < lower || msu >= 10u16.pow(msu_digits as u32) {
+            return err("digit count does not match coefficient");
+        }
+        if specials == 0 && !exponent_in_range(digits, exponent) {
+            return err("exponent out of range");
+        }
+        // Infinities have no coefficient.
+        if specials == decnumber_sys::DECINF && (n_digits != 1 || lsu[0] != 0) {
+            return err("infinity with a coefficient");
+        }
+        // Units beyond those holding the coefficient are unused, and may
+        // contain garbage.
+        for unit in &mut lsu[units..] {
+            *unit = 0;
+        }
+        Ok(Decimal {
+            digits,
+            exponent,
+            bits,
+            lsu,
+        })
+    }
+
+    /// Constructs a logical operand from a string of zeros and ones, like
+    /// `"1010"`.
+    ///
+    /// Returns an error if the string is empty, contains any character other
+    /// than `0` or `1`, or has more than [`Decimal::MAX_PRECISION`] digits.
+    /// The result always satisfies [`Decimal::is_logical`].
+    pub fn from_logical_str(s: &str) -> Result<Decimal<N>, InvalidLogicalOperandError> {
+        if s.is_empty() {
+            return Err(InvalidLogicalOperandError::new("empty string"));
+        }
+        if s.bytes().any(|b| b != b'0' && b != b'1') {
+            return Err(InvalidLogicalOperandError::new("digit other than 0 or 1"));
+        }
+        if s.len() > Decimal::<N>::MAX_PRECISION {
+            return Err(InvalidLogicalOperandError::new("too many digits"));
+        }
+        let mut d = Decimal::<N>::zero();
+        let mut digits = 1;
+        for (i, b) in s.bytes().rev().enumerate() {
+            if b == b'1' {
+                d.lsu[i / decnumber_sys::DECDPUN] += 10u16.pow((i % decnumber_sys::DECDPUN) as u32);
+                digits = i + 1;
+            }
+        }
+        d.digits = u32::try_from(digits).unwrap();
+        Ok(d)
+    }
+
+    /// Returns a self-describing binary encoding of this decimal.
+    ///
+    /// Unlike the raw parts of the decimal, the encoding does not depend on
+    /// `N`, so it can be decoded by [`Decimal::from_tagged_bytes`] into a
+    /// decimal of any width with enough capacity for the coefficient. The
+    /// encoding consists of:
+    ///
+    ///   * a version byte, currently `1`;
+    ///   * the number of digits in the coefficient, as a little-endian `u32`;
+    ///   * a byte of flags describing the sign and any special value;
+    ///   * the exponent, as a little-endian `i32`;
+    ///   * the digits of the coefficient, most significant first, packed two
+    ///     per byte as binary-coded decimal. If the number of digits is odd,
+    ///     the low nibble of the last byte is zero.
+    ///
+    /// The encoding preserves the sign, exponent, and coefficient exactly,
+    /// including the payloads of NaNs.
+    pub fn to_tagged_bytes(&self) -> Vec<u8> {
+        let digits = self.coefficient_digits();
+        let mut buf =
+            Vec::with_capacity(TAGGED_BYTES_HEADER_LEN + digits.len() / 2 + digits.len() % 2);
+        buf.push(TAGGED_BYTES_VERSION);
+        buf.extend_from_slice(&self.digits.to_le_bytes());
+        buf.push(self.bits);
+        buf.extend_from_slice(&self.exponent.to_le_bytes());
+        pack_bcd(&digits, &mut buf);
+        buf
+    }
+
+    /// Decodes a decimal from the encoding produced by
+    /// [`Decimal::to_tagged_bytes`].
+    ///
+    /// Returns an error if `bytes` is not a valid encoding, e.g. because it
+    /// has an unknown version or is truncated, or if the encoded coefficient
+    /// has more digits than a `Decimal<N>` can hold.
+    pub fn from_tagged_bytes(bytes: &[u8]) -> Result<Decimal<N>, InvalidTaggedBytesError> {
+        validate_n(N);
+        if bytes.len() < TAGGED_BYTES_HEADER_LEN || bytes[0] != TAGGED_BYTES_VERSION {
+            return Err(InvalidTaggedBytesError);
+        }
+        let digits = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
+        let bits = bytes[5];
+        let exponent = i32::from_le_bytes(bytes[6..10].try_into().unwrap());
+        let packed = &bytes[TAGGED_BYTES_HEADER_LEN..];
+        Decimal::from_packed_parts(digits, bits, exponent, packed).ok_or(InvalidTaggedBytesError)
+    }
+
+    /// Returns a compact, self-describing binary encoding of this decimal.
+    ///
+    /// As with [`Decimal::to_tagged_bytes`], the encoding does not depend on
+    /// `N`, so it can be decoded by [`Decimal::from_canonical_bytes`] into a
+    /// decimal of any width with enough capacity for the coefficient. It is,
+    /// however, more compact for the small digit counts and exponents that are
+    /// typical in practice. The encoding consists of:
+    ///
+    ///   * a version byte, currently `1`;
+    ///   * a byte of flags describing the sign and any special value;
+    ///   * the number of digits in the coefficient, as an unsigned LEB128
+    ///     varint;
+    ///   * the exponent, zigzag-encoded as an unsigned LEB128 varint, so that
+    ///     exponents near zero of either sign are short;
+    ///   * the digits of the coefficient, most significant first, packed two
+    ///     per byte as binary-coded decimal. If the number of digits is odd,
+    ///     the low nibble of the last byte is zero.
+    ///
+    /// The encoding preserves the sign, exponent, and coefficient exactly,
+    /// including the payloads of NaNs. It is canonical in that every decimal
+    /// has exactly one encoding: `from_canonical_bytes` rejects varints that
+    /// are not in their shortest form, and any other redundancy, like leading
+    /// zeros in the coefficient.
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let digits = self.coefficient_digits();
+        let mut buf = Vec::with_capacity(12 + digits.len() / 2 + digits.len() % 2);
+        buf.push(CANONICAL_BYTES_VERSION);
+        buf.push(self.bits);
+        write_varint(&mut buf, self.digits);
+        write_varint(
+            &mut buf,
+            ((self.exponent << 1) ^ (self.exponent >> 31)) as u32,
+        );
+        pack_bcd(&digits, &mut buf);
+        buf
+    }
+
+    /// Decodes a decimal from the encoding produced by
+    /// [`Decimal::to_canonical_bytes`].
+    ///
+    /// Returns an error if `bytes` is not the canonical encoding of any
+    /// decimal, e.g. because it has an unknown version, is truncated, or
+    /// contains a varint that is not in its shortest form, or if the encoded
+    /// coefficient has more digits than a `Decimal<N>` can hold.
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Result<Decimal<N>, InvalidCanonicalBytesError> {
+        validate_n(N);
+        let (version, bits, mut rest) = match bytes {
+            [version, bits, rest @ ..] => (*version, *bits, rest),
+            _ => return Err(InvalidCanonicalBytesError),
+        };
+        if version != CANONICAL_BYTES_VERSION {
+            return Err(InvalidCanonicalBytesError);
+        }
+        let digits = read_varint(&mut rest).ok_or(InvalidCanonicalBytesError)?;
+        let zigzag = read_varint(&mut rest).ok_or(InvalidCanonicalBytesError)?;
+        let exponent = ((zigzag >> 1) as i32) ^ -((zigzag & 1) as i32);
+        Decimal::from_packed_parts(digits, bits, exponent, rest).ok_or(InvalidCanonicalBytesError)
+    }
+
+    // Assembles a decimal from the parts of its tagged or canonical byte
+    // encoding, where `packed` holds the digits of its coefficient as
+    // binary-coded decimal, or returns `None` if the parts are invalid.
+    fn from_packed_parts(
+        digits: u32,
+        bits: u8,
+        exponent: i32,
+        packed: &[u8],
+    ) -> Option<Decimal<N>> {
+        let specials = bits & decnumber_sys::DECSPECIAL;
+        if bits & !(decnumber_sys::DECNEG | decnumber_sys::DECSPECIAL) != 0
+            || specials.count_ones() > 1
+        {
+            return None;
+        }
+        let digit_count = usize::try_from(digits).ok()?;
+        if digit_count == 0 || digit_count > N * decnumber_sys::DECDPUN {
+            return None;
+        }
+        if packed.len() != digit_count / 2 + digit_count % 2 {
+            return None;
+        }
+        let mut bcd = Vec::with_capacity(digit_count + 1);
+        for byte in packed {
+            bcd.push(byte >> 4);
+            bcd.push(byte & 0x0f);
+        }
+        if bcd.iter().any(|d| *d > 9)
+            || bcd[digit_count..].iter().any(|d| *d != 0)
+            || (digit_count > 1 && bcd[0] == 0)
+        {
+            return None;
+        }
+        if specials == 0 && !exponent_in_range(digits, exponent) {
+            return None;
+        }
+        // Infinities have no coefficient.
+        if specials == decnumber_sys::DECINF && (digit_count != 1 || bcd[0] != 0) {
+            return None;
+        }
+
+        // `decNumberSetBCD` locates the most significant unit using the
+        // existing digit count, so it must be set first.
+        let mut d = Decimal::<N>::zero();
+        d.digits = digits;
+        unsafe {
+            decnumber_sys::decNumberSetBCD(d.as_mut_ptr(), bcd.as_ptr(), digits);
+        }
+        d.exponent = exponent;
+        d.bits = bits;
+        Some(d)
+    }
+
     /// Returns a string of the number in standard notation, i.e. guaranteed to
     /// not be scientific notation.
     pub fn to_standard_notation_string(&self) -> String {
         to_standard_notation_string!(self)
     }
+
+    /// Returns a string of the number in engineering notation, in which any
+    /// exponent is a multiple of three.
+    ///
+    /// This is the same string produced by the alternate form of the
+    /// [`Display`](fmt::Display) implementation, `format!("{:#}", d)`, while
+    /// the default form, `d.to_string()`, uses scientific notation. For
+    /// example, `1.23E+4` is `12.3E+3` in engineering notation, while numbers
+    /// that need no exponent, like `123.45`, are the same in both.
+    pub fn to_engineering_string(&self) -> String {
+        let mut s = String::new();
+        self.write_with(&mut s, true)
+            .expect("writing to a String cannot fail");
+        s
+    }
 }
 
 impl<const N: usize> Default for Decimal<N> {
     fn default() -> Decimal<N> {
-        validate_n(N);
-        Decimal::<N> {
-            digits: 1,
-            bits: 0,
-            exponent: 0,
-            lsu: [0; N],
-        }
+        Decimal::ZERO
     }
 }
 
@@ -344,6 +1602,74 @@ impl<const N: usize> PartialEq for Decimal<N> {
     }
 }
 
+/// Implements comparisons in both directions between `Decimal<N>` and the
+/// primitive integer type `$p`, using `$to_decimal` to compare a decimal with
+/// a primitive.
+macro_rules! decimal_cmp_primitive {
+    ($p:ty, $to_decimal:ident) => {
+        impl<const N: usize> PartialEq<$p> for Decimal<N> {
+            fn eq(&self, other: &$p) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl<const N: usize> PartialOrd<$p> for Decimal<N> {
+            fn partial_cmp(&self, other: &$p) -> Option<Ordering> {
+                self.$to_decimal(*other)
+            }
+        }
+
+        impl<const N: usize> PartialEq<Decimal<N>> for $p {
+            fn eq(&self, other: &Decimal<N>) -> bool {
+                other == self
+            }
+        }
+
+        impl<const N: usize> PartialOrd<Decimal<N>> for $p {
+            fn partial_cmp(&self, other: &Decimal<N>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+decimal_cmp_primitive!(i32, cmp_primitive);
+decimal_cmp_primitive!(i64, cmp_primitive);
+decimal_cmp_primitive!(u32, cmp_primitive);
+decimal_cmp_primitive!(u64, cmp_primitive);
+decimal_cmp_primitive!(i128, cmp_i128);
+decimal_cmp_primitive!(u128, cmp_u128);
+
+impl<const N: usize> Decimal<N> {
+    fn cmp_primitive<P>(&self, n: P) -> Option<Ordering>
+    where
+        Decimal<N>: From<P>,
+    {
+        self.partial_cmp(&Decimal::from(n))
+    }
+
+    // The 128-bit integers have up to 39 digits, which do not fit in a
+    // `Decimal<12>`, so the comparison widens to `Decimal<13>` if necessary to
+    // keep the conversion exact.
+    fn cmp_i128(&self, n: i128) -> Option<Ordering> {
+        if N >= 13 {
+            self.partial_cmp(&Context::<Decimal<N>>::default().from_i128(n))
+        } else {
+            let mut cx = Context::<Decimal<13>>::default();
+            cx.to_width(*self).partial_cmp(&cx.from_i128(n))
+        }
+    }
+
+    fn cmp_u128(&self, n: u128) -> Option<Ordering> {
+        if N >= 13 {
+            self.partial_cmp(&Context::<Decimal<N>>::default().from_u128(n))
+        } else {
+            let mut cx = Context::<Decimal<13>>::default();
+            cx.to_width(*self).partial_cmp(&cx.from_u128(n))
+        }
+    }
+}
+
 impl<const N: usize> fmt::Debug for Decimal<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -352,22 +1678,7 @@ impl<const N: usize> fmt::Debug for Decimal<N> {
 
 impl<const N: usize> fmt::Display for Decimal<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // String conversion may need up to `self.digits + 14` characters,
-        // per the libdecnumber documentation.
-        let mut buf = Vec::with_capacity(self.digits as usize + 14);
-        let c_str = unsafe {
-            if f.alternate() {
-                decnumber_sys::decNumberToEngString(self.as_ptr(), buf.as_mut_ptr() as *mut c_char);
-            } else {
-                decnumber_sys::decNumberToString(self.as_ptr(), buf.as_mut_ptr() as *mut c_char);
-            }
-            CStr::from_ptr(buf.as_ptr() as *const c_char)
-        };
-        f.write_str(
-            c_str
-                .to_str()
-                .expect("decNumberToString yields valid UTF-8"),
-        )
+        self.write_with(f, f.alternate())
     }
 }
 
@@ -447,8 +1758,7 @@ macro_rules! decnum_tryinto_primitive_uint {
 impl<const N: usize> TryFrom<Decimal<N>> for i32 {
     type Error = TryFromDecimalError;
     fn try_from(n: Decimal<N>) -> Result<i32, Self::Error> {
-        let mut cx = Context::<Decimal<N>>::default();
-        cx.try_into_i32(n)
+        n.to_i32()
     }
 }
 
@@ -457,8 +1767,7 @@ impl<const N: usize> TryFrom<Decimal<N>> for i32 {
 impl<const N: usize> TryFrom<Decimal<N>> for u32 {
     type Error = TryFromDecimalError;
     fn try_from(n: Decimal<N>) -> Result<u32, Self::Error> {
-        let mut cx = Context::<Decimal<N>>::default();
-        cx.try_into_u32(n)
+        n.to_u32()
     }
 }
 
@@ -578,6 +1887,65 @@ impl<const N: usize> From<isize> for Decimal<N> {
     }
 }
 
+// A buffer on the stack that a float's `{:e}` form is written into, so that
+// converting a float to a decimal does not allocate. The longest such form,
+// that of an `f64` like `-2.2250738585072014e-308`, is 24 bytes long.
+struct FloatBuf {
+    buf: [u8; 32],
+    len: usize,
+}
+
+impl fmt::Write for FloatBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Implements `TryFrom<$f>` for `Decimal<N>`, where `$f` is a primitive
+/// floating-point type.
+macro_rules! decimal_try_from_float {
+    ($f:ty) => {
+        /// Converts a float to the shortest decimal that rounds back to the same
+        /// float, as printed by the float's `Display` implementation.
+        ///
+        /// For example, `0.1` converts to exactly `0.1`, not to the exact value
+        /// of the binary float nearest to one tenth. The conversion is always
+        /// exact for finite floats, as even the smallest `Decimal<N>` has room
+        /// for more digits than any float needs, and preserves the sign of
+        /// negative zero. NaNs and infinities produce an error, which is the
+        /// only way the conversion can fail. The conversion does not
+        /// allocate.
+        impl<const N: usize> TryFrom<$f> for Decimal<N> {
+            type Error = TryFromFloatError;
+            fn try_from(f: $f) -> Result<Decimal<N>, TryFromFloatError> {
+                if !f.is_finite() {
+                    return Err(TryFromFloatError);
+                }
+                let mut buf = FloatBuf {
+                    buf: [0; 32],
+                    len: 0,
+                };
+                write!(buf, "{:e}", f).expect("float formats fit in the buffer");
+                let mut cx = Context::<Decimal<N>>::default();
+                let d = cx
+                    .parse_bytes(&buf.buf[..buf.len])
+                    .expect("finite floats format as valid decimals");
+                debug_assert!(!cx.status().any());
+                Ok(d)
+            }
+        }
+    };
+}
+
+decimal_try_from_float!(f32);
+decimal_try_from_float!(f64);
+
 impl<const N: usize> From<Decimal32> for Decimal<N> {
     fn from(n: Decimal32) -> Decimal<N> {
         let mut d = Decimal::default();
@@ -631,6 +1999,44 @@ impl<const N: usize> Neg for Decimal<N> {
     }
 }
 
+impl<const N: usize> Neg for &Decimal<N> {
+    type Output = Decimal<N>;
+
+    /// Like the by-value implementation, this copies `self` to generate the
+    /// negative value.
+    fn neg(self) -> Decimal<N> {
+        let mut n = *self;
+        unsafe {
+            decnumber_sys::decNumberCopyNegate(n.as_mut_ptr(), n.as_ptr());
+        }
+        n
+    }
+}
+
+impl<const N: usize> AddAssign<&Decimal<N>> for Decimal<N> {
+    fn add_assign(&mut self, rhs: &Decimal<N>) {
+        Context::<Decimal<N>>::default().add(self, rhs);
+    }
+}
+
+impl<const N: usize> DivAssign<&Decimal<N>> for Decimal<N> {
+    fn div_assign(&mut self, rhs: &Decimal<N>) {
+        Context::<Decimal<N>>::default().div(self, rhs);
+    }
+}
+
+impl<const N: usize> MulAssign<&Decimal<N>> for Decimal<N> {
+    fn mul_assign(&mut self, rhs: &Decimal<N>) {
+        Context::<Decimal<N>>::default().mul(self, rhs);
+    }
+}
+
+impl<const N: usize> SubAssign<&Decimal<N>> for Decimal<N> {
+    fn sub_assign(&mut self, rhs: &Decimal<N>) {
+        Context::<Decimal<N>>::default().sub(self, rhs);
+    }
+}
+
 impl<const N: usize> Default for Context<Decimal<N>> {
     fn default() -> Context<Decimal<N>> {
         let mut ctx = MaybeUninit::<decnumber_sys::decContext>::uninit();
@@ -645,12 +2051,48 @@ impl<const N: usize> Default for Context<Decimal<N>> {
             .expect("decimal digit count does not fit into i32");
         Context {
             inner: ctx,
+            status_policy: StatusPolicy::Ignore,
             _phantom: PhantomData,
         }
     }
 }
 
+impl<const N: usize> ContextBuilder<Decimal<N>> {
+    /// Builds the configured context.
+    ///
+    /// Returns an error if any configured property would be rejected by the
+    /// corresponding setter on [`Context<Decimal<N>>`], e.g. a precision
+    /// greater than `N * 3`.
+    pub fn build(self) -> Result<Context<Decimal<N>>, InvalidContextError> {
+        let mut cx = Context::<Decimal<N>>::default();
+        if let Some(precision) = self.precision {
+            cx.set_precision(precision)?;
+        }
+        if let Some(rounding) = self.rounding {
+            cx.set_rounding(rounding);
+        }
+        if let Some(e) = self.max_exponent {
+            cx.set_max_exponent(e)?;
+        }
+        if let Some(e) = self.min_exponent {
+            cx.set_min_exponent(e)?;
+        }
+        if let Some(clamp) = self.clamp {
+            cx.set_clamp(clamp);
+        }
+        Ok(cx)
+    }
+}
+
 impl<const N: usize> Context<Decimal<N>> {
+    /// The largest maximum exponent accepted by
+    /// [`set_max_exponent`](Context::<Decimal<N>>::set_max_exponent).
+    pub const MAX_EMAX: isize = 999_999_999;
+
+    /// The smallest minimum exponent accepted by
+    /// [`set_min_exponent`](Context::<Decimal<N>>::set_min_exponent).
+    pub const MIN_EMIN: isize = -999_999_999;
+
     /// Returns the context's precision.
     ///
     /// Operations that use this context will be rounded to this length if
@@ -659,15 +2101,70 @@ impl<const N: usize> Context<Decimal<N>> {
         usize::try_from(self.inner.digits).expect("context digit count does not fit into usize")
     }
 
-    /// Sets the context's precision.
-    ///
-    /// The precision must be greater than one and no greater than `N * 3`.
-    pub fn set_precision(&mut self, precision: usize) -> Result<(), InvalidPrecisionError> {
-        if precision < 1 || precision > N * decnumber_sys::DECDPUN {
-            return Err(InvalidPrecisionError);
+    /// Sets the context's precision.
+    ///
+    /// The precision must be greater than one and no greater than
+    /// [`Decimal::<N>::MAX_PRECISION`].
+    pub fn set_precision(&mut self, precision: usize) -> Result<(), InvalidPrecisionError> {
+        if precision < 1 || precision > Decimal::<N>::MAX_PRECISION {
+            return Err(InvalidPrecisionError);
+        }
+        self.inner.digits = i32::try_from(precision).map_err(|_| InvalidPrecisionError)?;
+        Ok(())
+    }
+
+    /// Runs `f` against this context with its precision temporarily set to
+    /// `precision`, returning the result of `f`.
+    ///
+    /// The previous precision is restored when `f` returns, or if `f` panics.
+    /// This is useful for computing intermediate results with extra digits
+    /// before rounding the final result back to the original precision. Any
+    /// other changes that `f` makes to the context, including to its status,
+    /// are retained.
+    ///
+    /// The precision is validated as for
+    /// [`set_precision`](Context::<Decimal<N>>::set_precision) before `f` is
+    /// run; if it is invalid, an error is returned and `f` is not run.
+    ///
+    /// ```
+    /// use dec::{Context, Decimal};
+    /// let mut cx = Context::<Decimal<12>>::default();
+    /// cx.set_precision(5).unwrap();
+    /// let third = cx
+    ///     .with_precision(10, |cx| {
+    ///         let mut n = Decimal::from(1);
+    ///         cx.div(&mut n, &Decimal::from(3));
+    ///         n
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(third.to_string(), "0.3333333333");
+    /// assert_eq!(cx.precision(), 5);
+    /// ```
+    pub fn with_precision<R, F>(
+        &mut self,
+        precision: usize,
+        f: F,
+    ) -> Result<R, InvalidPrecisionError>
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        // Restores the saved precision when dropped, including during
+        // unwinding.
+        struct Guard<'a, const N: usize> {
+            cx: &'a mut Context<Decimal<N>>,
+            saved: i32,
+        }
+
+        impl<const N: usize> Drop for Guard<'_, N> {
+            fn drop(&mut self) {
+                self.cx.inner.digits = self.saved;
+            }
         }
-        self.inner.digits = i32::try_from(precision).map_err(|_| InvalidPrecisionError)?;
-        Ok(())
+
+        let saved = self.inner.digits;
+        self.set_precision(precision)?;
+        let guard = Guard { cx: self, saved };
+        Ok(f(guard.cx))
     }
 
     /// Reports whether the context has exponent clamping enabled.
@@ -676,11 +2173,17 @@ impl<const N: usize> Context<Decimal<N>> {
     /// [decContext module] for details.
     ///
     /// [decContext module]: http://speleotrove.com/decimal/dncont.html
+    ///
+    /// Exponent clamping is unrelated to clamping a number into a range of
+    /// values. For the latter, see
+    /// [`clamp_value`](Context::<Decimal<N>>::clamp_value).
     pub fn clamp(&self) -> bool {
         self.inner.clamp != 0
     }
 
     /// Sets whether the context has exponent clamping enabled.
+    ///
+    /// See [`clamp`](Context::<Decimal<N>>::clamp) for details.
     pub fn set_clamp(&mut self, clamp: bool) {
         self.inner.clamp = u8::from(clamp)
     }
@@ -698,9 +2201,9 @@ impl<const N: usize> Context<Decimal<N>> {
     /// Sets the context's maximum exponent.
     ///
     /// The maximum exponent must not be negative and no greater than
-    /// 999,999,999.
+    /// [`MAX_EMAX`](Context::<Decimal<N>>::MAX_EMAX), 999,999,999.
     pub fn set_max_exponent(&mut self, e: isize) -> Result<(), InvalidExponentError> {
-        if e < 0 || e > 999999999 {
+        if !(0..=Self::MAX_EMAX).contains(&e) {
             return Err(InvalidExponentError);
         }
         self.inner.emax = i32::try_from(e).map_err(|_| InvalidExponentError)?;
@@ -720,9 +2223,9 @@ impl<const N: usize> Context<Decimal<N>> {
     /// Sets the context's minimum exponent.
     ///
     /// The minimum exponent must not be positive and no smaller than
-    /// -999,999,999.
+    /// [`MIN_EMIN`](Context::<Decimal<N>>::MIN_EMIN), -999,999,999.
     pub fn set_min_exponent(&mut self, e: isize) -> Result<(), InvalidExponentError> {
-        if e > 0 || e < -999999999 {
+        if !(Self::MIN_EMIN..=0).contains(&e) {
             return Err(InvalidExponentError);
         }
         self.inner.emin = i32::try_from(e).map_err(|_| InvalidExponentError)?;
@@ -735,23 +2238,174 @@ impl<const N: usize> Context<Decimal<N>> {
         S: Into<Vec<u8>>,
     {
         validate_n(N);
-        let c_string = CString::new(s).map_err(|_| ParseDecimalError)?;
+        let c_string = CString::new(s).map_err(|e| ParseDecimalError::diagnose(&e.into_vec()))?;
+        self.parse_c_str(&c_string)
+    }
+
+    /// Parses a number from its string representation, provided as bytes.
+    ///
+    /// The syntax is the same as for [`parse`](Context::<Decimal<N>>::parse).
+    /// As that syntax is ASCII-only, the bytes are not checked to be valid
+    /// UTF-8; any non-ASCII byte simply produces a parse error. This avoids
+    /// the cost of converting data read from a network buffer or file into a
+    /// `str` first.
+    ///
+    /// libdecnumber requires a NUL-terminated string, so the bytes are
+    /// copied into a buffer with a terminator. For inputs shorter than 64
+    /// bytes, the buffer is on the stack and no allocation is performed.
+    pub fn parse_bytes(&mut self, b: &[u8]) -> Result<Decimal<N>, ParseDecimalError> {
+        validate_n(N);
+        if b.contains(&0) {
+            return Err(ParseDecimalError::diagnose(b));
+        }
+        let mut buf = [0; PARSE_BYTES_STACK_LEN];
+        if b.len() < buf.len() {
+            buf[..b.len()].copy_from_slice(b);
+            let c_str = CStr::from_bytes_with_nul(&buf[..=b.len()])
+                .expect("buffer is NUL-terminated without interior NULs");
+            self.parse_c_str(c_str)
+        } else {
+            let c_string = CString::new(b).expect("bytes contain no interior NULs");
+            self.parse_c_str(&c_string)
+        }
+    }
+
+    // Parses a number from a C string, as for `parse`.
+    fn parse_c_str(&mut self, s: &CStr) -> Result<Decimal<N>, ParseDecimalError> {
         let mut d = MaybeUninit::<Decimal<N>>::uninit();
         let d = unsafe {
             decnumber_sys::decNumberFromString(
                 d.as_mut_ptr() as *mut decnumber_sys::decNumber,
-                c_string.as_ptr(),
+                s.as_ptr(),
                 &mut self.inner,
             );
             d.assume_init()
         };
         if (self.inner.status & decnumber_sys::DEC_Conversion_syntax) != 0 {
-            Err(ParseDecimalError)
+            Err(ParseDecimalError::diagnose(s.to_bytes()))
         } else {
             Ok(d)
         }
     }
 
+    /// Parses a ratio of the form `numerator/denominator`, like `3/4`, into
+    /// the decimal number that results from dividing the numerator by the
+    /// denominator.
+    ///
+    /// The numerator and denominator may use any syntax accepted by
+    /// [`parse`](Context::<Decimal<N>>::parse). A string with no `/` is parsed
+    /// as a plain decimal number. The position of any parse error is relative
+    /// to the start of `s`.
+    ///
+    /// The division is subject to this context's precision and rounding. If
+    /// the quotient does not terminate within the context's precision, as
+    /// with `1/3`, the result is rounded and the inexact and rounded flags are
+    /// set, as for [`div`](Context::<Decimal<N>>::div). Likewise, a zero
+    /// denominator sets the division by zero flag rather than producing an
+    /// error.
+    pub fn parse_ratio(&mut self, s: &str) -> Result<Decimal<N>, ParseDecimalError> {
+        match s.split_once('/') {
+            None => self.parse(s),
+            Some((numerator, denominator)) => {
+                let mut n = self.parse(numerator)?;
+                let d = self
+                    .parse(denominator)
+                    .map_err(|e| e.offset(numerator.len() + 1))?;
+                self.div(&mut n, &d);
+                Ok(n)
+            }
+        }
+    }
+
+    /// Parses a number written with locale-specific separators, like
+    /// `1.234,56` or `1 234.56`.
+    ///
+    /// Occurrences of `decimal` are treated as the decimal point, and
+    /// occurrences of `grouping`, if specified, are removed; the result is
+    /// then parsed as by [`parse`](Context::<Decimal<N>>::parse). The placement
+    /// of grouping separators is not validated. If `decimal` is not `.`, a `.`
+    /// that is not the grouping separator is rejected rather than treated as a
+    /// decimal point. If `grouping` is the same as `decimal`, it is treated as
+    /// the decimal point.
+    ///
+    /// The position of any parse error is relative to the start of `s`.
+    pub fn parse_localized(
+        &mut self,
+        s: &str,
+        decimal: char,
+        grouping: Option<char>,
+    ) -> Result<Decimal<N>, ParseDecimalError> {
+        let mut normalized = String::with_capacity(s.len());
+        // The offset in `s` of each byte in `normalized`, for translating the
+        // positions in parse errors.
+        let mut offsets = Vec::with_capacity(s.len());
+        for (i, c) in s.char_indices() {
+            if c == decimal {
+                normalized.push('.');
+                offsets.push(i);
+            } else if Some(c) == grouping {
+                continue;
+            } else if c == '.' {
+                return Err(ParseDecimalError::new(
+                    ParseDecimalErrorKind::UnexpectedCharacter,
+                    Some(i),
+                ));
+            } else {
+                normalized.push(c);
+                offsets.extend((0..c.len_utf8()).map(|j| i + j));
+            }
+        }
+        self.parse(normalized).map_err(|e| {
+            let position = e
+                .position()
+                .map(|p| offsets.get(p).copied().unwrap_or(s.len()));
+            ParseDecimalError::new(e.kind(), position)
+        })
+    }
+
+    /// Parses a number that may use underscores as digit separators, as in
+    /// Rust numeric literals like `1_000.5`.
+    ///
+    /// Each underscore must appear between two digits, optionally among other
+    /// underscores, as in `1__000`. Underscores at the start or end of the
+    /// number, or next to a sign, decimal point, or exponent indicator, are
+    /// rejected with an error of kind
+    /// [`UnexpectedCharacter`](ParseDecimalErrorKind::UnexpectedCharacter).
+    /// The underscores are otherwise removed, and the result is parsed as by
+    /// [`parse`](Context::<Decimal<N>>::parse), so a leading `+` or `-` is
+    /// accepted as usual. Type suffixes like `f64` are not accepted.
+    ///
+    /// The position of any parse error is relative to the start of `s`.
+    pub fn parse_rust_literal(&mut self, s: &str) -> Result<Decimal<N>, ParseDecimalError> {
+        let bytes = s.as_bytes();
+        let mut normalized = Vec::with_capacity(bytes.len());
+        // The offset in `s` of each byte in `normalized`, for translating the
+        // positions in parse errors.
+        let mut offsets = Vec::with_capacity(bytes.len());
+        for (i, b) in bytes.iter().enumerate() {
+            if *b == b'_' {
+                let before = bytes[..i].iter().rev().find(|b| **b != b'_');
+                let after = bytes[i + 1..].iter().find(|b| **b != b'_');
+                let is_digit = |b: Option<&u8>| matches!(b, Some(b) if b.is_ascii_digit());
+                if !is_digit(before) || !is_digit(after) {
+                    return Err(ParseDecimalError::new(
+                        ParseDecimalErrorKind::UnexpectedCharacter,
+                        Some(i),
+                    ));
+                }
+            } else {
+                normalized.push(*b);
+                offsets.push(i);
+            }
+        }
+        self.parse_bytes(&normalized).map_err(|e| {
+            let position = e
+                .position()
+                .map(|p| offsets.get(p).copied().unwrap_or(s.len()));
+            ParseDecimalError::new(e.kind(), position)
+        })
+    }
+
     /// Classifies the number.
     pub fn class(&mut self, n: &Decimal<N>) -> Class {
         Class::from_c(unsafe { decnumber_sys::decNumberClass(n.as_ptr(), &mut self.inner) })
@@ -768,6 +2422,89 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Spells out the monetary amount `n` in English words, in the style used
+    /// when writing checks.
+    ///
+    /// `n` is first quantized to exactly two fractional digits, which must
+    /// not require rounding, so `123.4` and `123.450` are accepted but
+    /// `123.456` is not. The whole part is then spelled out in words and the
+    /// fractional part is written as a fraction of 100.
+    ///
+    /// ```
+    /// use dec::{Context, Decimal};
+    /// let mut cx = Context::<Decimal<12>>::default();
+    /// let n = cx.parse("123.45").unwrap();
+    /// assert_eq!(
+    ///     cx.amount_to_words(&n).unwrap(),
+    ///     "one hundred twenty-three dollars and 45/100",
+    /// );
+    /// ```
+    ///
+    /// Returns an error with the invalid operation flag set if `n` is negative,
+    /// including negative zero, or not finite, if `n` cannot be quantized to
+    /// two fractional digits without rounding or within the context's
+    /// precision, or if the whole part is too large to be named (i.e., is not
+    /// less than 10<sup>36</sup>).
+    pub fn amount_to_words(&mut self, n: &Decimal<N>) -> Result<String, DecimalError> {
+        let mut invalid = Status::default();
+        invalid.set_invalid_operation();
+        if !n.is_finite() || n.is_negative() {
+            self.set_status(self.status() | invalid);
+            return Err(DecimalError::new(invalid));
+        }
+
+        let mut cents = Decimal::<N>::zero();
+        cents.set_exponent(-2);
+        let (amount, status) = self.isolate_status(|cx| {
+            let mut amount = *n;
+            cx.quantize(&mut amount, &cents);
+            amount
+        });
+        if status.invalid_operation() || status.inexact() || amount.exponent() != -2 {
+            let status = status | invalid;
+            self.set_status(self.status() | status);
+            return Err(DecimalError::new(status));
+        }
+
+        // Pad the coefficient so there is always at least one whole digit
+        // before the two fractional digits.
+        let mut digits = amount.coefficient_digits();
+        while digits.len() < 3 {
+            digits.insert(0, 0);
+        }
+        let (whole, fraction) = digits.split_at(digits.len() - 2);
+        let whole = match whole.iter().position(|d| *d != 0) {
+            Some(i) => &whole[i..],
+            None => &[],
+        };
+        if whole.len() > 3 * SCALE_WORDS.len() {
+            self.set_status(self.status() | invalid);
+            return Err(DecimalError::new(invalid));
+        }
+
+        let mut words = Vec::new();
+        if whole.is_empty() {
+            words.push(String::from("zero"));
+        }
+        for (scale, group) in whole.rchunks(3).enumerate().rev() {
+            let group = group.iter().fold(0, |acc, d| acc * 10 + usize::from(*d));
+            if group != 0 {
+                words.push(three_digits_to_words(group));
+                if scale > 0 {
+                    words.push(String::from(SCALE_WORDS[scale - 1]));
+                }
+            }
+        }
+        let unit = if whole == [1] { "dollar" } else { "dollars" };
+        Ok(format!(
+            "{} {} and {}{}/100",
+            words.join(" "),
+            unit,
+            fraction[0],
+            fraction[1]
+        ))
+    }
+
     /// Adds `lhs` and `rhs`, storing the result in `lhs`.
     pub fn add(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
         unsafe {
@@ -793,6 +2530,240 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Computes the cube root of `n`, storing the result in `n`.
+    ///
+    /// libdecnumber has no cube root primitive, so the root is estimated with
+    /// [`pow`](Context::<Decimal<N>>::pow) and then refined with one
+    /// Newton–Raphson step. Both steps are carried out at the full precision
+    /// of `Decimal<N>` (`N * 3` digits) before the result is rounded to this
+    /// context's precision using its rounding mode.
+    ///
+    /// The result is always within one unit in the last place of the exact
+    /// cube root. If this context's precision leaves `g` spare digits in
+    /// `Decimal<N>`, the result is correctly rounded unless the exact root
+    /// lies within roughly 10<sup>-g</sup> units in the last place of a
+    /// rounding boundary. In particular, when this context's precision is
+    /// `N * 3` digits, as it is in the default context, the result is not
+    /// guaranteed to be correctly rounded.
+    ///
+    /// Exact cubes produce exact results with the ideal exponent (one third of
+    /// `n`'s exponent, rounded down), as with
+    /// [`sqrt`](Context::<Decimal<N>>::sqrt).
+    ///
+    /// Negative numbers produce the negated cube root of their magnitude.
+    /// Zeros, infinities, and NaNs are left unchanged.
+    pub fn cbrt(&mut self, n: &mut Decimal<N>) {
+        if n.is_special() || n.is_zero() {
+            return;
+        }
+        self.root_finite(n, 3);
+    }
+
+    // Computes the `degree`th root of the finite, nonzero `n`, as described
+    // for `cbrt`, storing the result in `n`. The root of a negative number is
+    // the negated root of its magnitude. `degree` must be in [1, 999,999].
+    fn root_finite(&mut self, n: &mut Decimal<N>, degree: u32) {
+        let negative = n.is_negative();
+        let exponent = n.exponent();
+        let d = i32::try_from(degree).unwrap();
+
+        // Scale the magnitude of `n` into [1, 10^d), so that the intermediate
+        // steps cannot overflow regardless of this context's exponent range:
+        // root(m * 10^dk) = root(m) * 10^k.
+        let k = (exponent + n.digits() as i32 - 1).div_euclid(d);
+        let mut m = *n;
+        m.bits &= !decnumber_sys::DECNEG;
+        m.set_exponent(exponent - d * k);
+
+        let precision = self.precision();
+        let mut wcx = ContextBuilder::<Decimal<N>>::new()
+            .precision(N * decnumber_sys::DECDPUN)
+            .max_exponent(999_999)
+            .min_exponent(-999_999)
+            .build()
+            .expect("root working context is valid");
+        let degree = Decimal::<N>::from(degree);
+        let mut reciprocal = Decimal::<N>::from(1);
+        wcx.div(&mut reciprocal, &degree);
+        let mut y = m;
+        wcx.pow(&mut y, &reciprocal);
+
+        // y' = y - (y - m / y^(d-1)) / d. Applying the step as a small
+        // correction to y, rather than computing ((d-1)y + m / y^(d-1)) / d
+        // directly, keeps the full working precision in the result's
+        // coefficient.
+        let mut power = y;
+        wcx.powi(&mut power, d - 1);
+        let mut q = m;
+        wcx.div(&mut q, &power);
+        let mut correction = y;
+        wcx.sub(&mut correction, &q);
+        wcx.div(&mut correction, &degree);
+        wcx.sub(&mut y, &correction);
+        y.set_exponent(y.exponent() + k);
+
+        let mut rcx = self.clone();
+        rcx.clear_status();
+        rcx.plus(&mut y);
+
+        // Determine whether the rounded root is exact by raising it to the
+        // `d`th power in a context with the full precision of `Decimal<N>`.
+        let mut ecx = Context::<Decimal<N>>::default();
+        let mut power = y;
+        ecx.powi(&mut power, d);
+        let mut magnitude = *n;
+        magnitude.bits &= !decnumber_sys::DECNEG;
+        let exact =
+            !ecx.status().inexact() && ecx.partial_cmp(&power, &magnitude) == Some(Ordering::Equal);
+
+        if exact {
+            ecx.reduce(&mut y);
+            let ideal = exponent.div_euclid(d);
+            if y.exponent() > ideal {
+                let room = i32::try_from(precision).unwrap_or(i32::MAX) - y.digits() as i32;
+                let mut target = Decimal::<N>::zero();
+                target.set_exponent(cmp::max(ideal, y.exponent() - room));
+                self.quantize(&mut y, &target);
+            }
+        } else {
+            let mut status = rcx.status();
+            status.set_inexact();
+            status.set_rounded();
+            self.set_status(self.status() | status);
+        }
+
+        if negative {
+            y.bits |= decnumber_sys::DECNEG;
+        }
+        *n = y;
+    }
+
+    /// Like [`fma`](Context::<Decimal<N>>::fma), but returns the result
+    /// rather than storing it in `x`, or returns an error if the operation
+    /// overflowed or was invalid.
+    ///
+    /// As with `fma`, the multiplication is exact and only the final result
+    /// is rounded.
+    ///
+    /// Note that libdecnumber restricts the exponent range of contexts used
+    /// for this operation to [-999,999, 999,999]. Using a context with a wider
+    /// range, like the default context, produces an error with the invalid
+    /// context flag set.
+    pub fn checked_fma(
+        &mut self,
+        x: &Decimal<N>,
+        y: &Decimal<N>,
+        z: &Decimal<N>,
+    ) -> Result<Decimal<N>, DecimalError> {
+        let (r, status) = self.isolate_status(|cx| {
+            let mut r = *x;
+            cx.fma(&mut r, y, z);
+            r
+        });
+        if status.overflow() || status.invalid_operation() || status.invalid_context() {
+            Err(DecimalError::new(status))
+        } else {
+            Ok(r)
+        }
+    }
+
+    /// Like [`rem`](Context::<Decimal<N>>::rem), but returns an error if the
+    /// remainder cannot be computed.
+    ///
+    /// The two causes of failure can be distinguished via the error's
+    /// [`status`](DecimalError::status):
+    ///
+    ///   * If `rhs` is zero, the invalid operation flag is set (or, if `lhs`
+    ///     is also zero, the division undefined flag).
+    ///   * If the integer quotient of the division has more digits than the
+    ///     context's precision, the division impossible flag is set.
+    ///
+    /// When an error is returned, `lhs` is left holding a NaN.
+    pub fn checked_rem(
+        &mut self,
+        lhs: &mut Decimal<N>,
+        rhs: &Decimal<N>,
+    ) -> Result<(), DecimalError> {
+        let ((), status) = self.isolate_status(|cx| cx.rem(lhs, rhs));
+        if status.invalid_operation() || status.division_undefined() || status.division_impossible()
+        {
+            Err(DecimalError::new(status))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Rounds `n` to this context's precision, returning the rounded value
+    /// and whether rounding occurred.
+    ///
+    /// The value is computed as by [`plus`](Context::<Decimal<N>>::plus), and
+    /// the flag reports whether `plus` raised the rounded condition, i.e.,
+    /// whether any digits were discarded. Discarded digits may all be zeros,
+    /// as when `1.000` is clamped to a precision of two digits, in which case
+    /// the value is unchanged but its exponent is not; check the context's
+    /// inexact flag to learn whether the value itself changed. Both conditions
+    /// are raised on this context as usual.
+    pub fn clamp_to_precision(&mut self, n: &Decimal<N>) -> (Decimal<N>, bool) {
+        let (r, status) = self.isolate_status(|cx| {
+            let mut r = *n;
+            cx.plus(&mut r);
+            r
+        });
+        (r, status.rounded())
+    }
+
+    /// Clamps `n` into the range `[min, max]`, storing the result in `n`.
+    ///
+    /// This is the decimal analog of [`f64::clamp`]. It restricts the *value*
+    /// of `n`, and has nothing to do with the context's exponent clamping
+    /// setting, which is controlled by
+    /// [`set_clamp`](Context::<Decimal<N>>::set_clamp).
+    ///
+    /// Comparisons use [`total_cmp`](Context::<Decimal<N>>::total_cmp), so
+    /// numerically equal values with different exponents are distinguished:
+    /// clamping `1.0` into `[1.00, 2]` produces `1.0`, while clamping `1.00`
+    /// into `[1.0, 2]` produces `1.0`.
+    ///
+    /// If `min` or `max` is a NaN, or `min` orders after `max`, `n` is set to
+    /// NaN and the invalid operation flag is raised. If `n` is a NaN, it is
+    /// left as a NaN; signaling NaNs are quieted and raise the invalid
+    /// operation flag, as with arithmetic operations.
+    pub fn clamp_value(&mut self, n: &mut Decimal<N>, min: &Decimal<N>, max: &Decimal<N>) {
+        if min.is_nan() || max.is_nan() || self.total_cmp(min, max) == Ordering::Greater {
+            self.set_invalid_operation();
+            *n = Decimal::nan();
+        } else if n.is_nan() {
+            self.plus(n);
+        } else if self.total_cmp(n, min) == Ordering::Less {
+            *n = *min;
+        } else if self.total_cmp(n, max) == Ordering::Greater {
+            *n = *max;
+        }
+    }
+
+    /// Returns an exact copy of `n`.
+    ///
+    /// The copy is made with libdecnumber's `decNumberCopy`, which ignores
+    /// this context entirely: the copy is never rounded to the context's
+    /// precision, no conditions are raised, and special values, including
+    /// signaling NaNs and negative zeros, are copied as is. Use
+    /// [`plus`](Context::<Decimal<N>>::plus) instead to obtain a copy that is
+    /// rounded to this context's precision and exponent range, as if it were
+    /// the result of an arithmetic operation in this context.
+    ///
+    /// The context is not needed to make the copy. This method is provided on
+    /// the context so that code which moves values into a context can choose
+    /// between `copy` and `plus` in one place, according to whether rounding
+    /// should occur. Outside of such code, copying a `Decimal` directly is
+    /// equivalent, as `Decimal` implements `Copy`.
+    pub fn copy(&mut self, n: &Decimal<N>) -> Decimal<N> {
+        let mut d = Decimal::<N>::zero();
+        unsafe {
+            decnumber_sys::decNumberCopy(d.as_mut_ptr(), n.as_ptr());
+        }
+        d
+    }
+
     /// Divides `lhs` by `rhs`, storing the result in `lhs`.
     pub fn div(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
         unsafe {
@@ -805,6 +2776,58 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Divides `lhs` by `rhs`, returning the quotient only if it is exact.
+    ///
+    /// An error is returned if the quotient had to be rounded to fit the
+    /// context's precision, i.e., if the division raised the inexact
+    /// condition, as well as if `rhs` is zero or the division otherwise raised
+    /// the division by zero, division undefined, overflow, underflow, or
+    /// invalid operation conditions. The error carries all of the conditions
+    /// raised by the division.
+    ///
+    /// ```
+    /// use dec::{Context, Decimal};
+    /// let mut cx = Context::<Decimal<12>>::default();
+    /// let q = cx.div_exact(&Decimal::from(100), &Decimal::from(8)).unwrap();
+    /// assert_eq!(q.to_string(), "12.5");
+    /// assert!(cx.div_exact(&Decimal::from(100), &Decimal::from(3)).is_err());
+    /// ```
+    pub fn div_exact(
+        &mut self,
+        lhs: &Decimal<N>,
+        rhs: &Decimal<N>,
+    ) -> Result<Decimal<N>, DecimalError> {
+        let (q, status) = self.isolate_status(|cx| {
+            let mut q = *lhs;
+            cx.div(&mut q, rhs);
+            q
+        });
+        if status.division_by_zero() || status.division_undefined() {
+            return Err(DecimalError::new(status));
+        }
+        Self::check_lossless(q, status)
+    }
+
+    /// Divides `lhs` by `rhs`, returning the integer part of the quotient and
+    /// the remainder.
+    ///
+    /// The results are those of [`div_integer`](Context::<Decimal<N>>::div_integer)
+    /// and [`rem`](Context::<Decimal<N>>::rem), respectively: the quotient is
+    /// truncated toward zero, and the remainder has the sign of `lhs`, so that
+    /// `q * rhs + r == lhs`. If the quotient does not fit within this
+    /// context's precision, both results are NaN and the division impossible
+    /// flag is set.
+    ///
+    /// libdecnumber does not provide a combined operation, so this performs
+    /// both divisions, but it spares callers from copying `lhs`.
+    pub fn div_rem(&mut self, lhs: &Decimal<N>, rhs: &Decimal<N>) -> (Decimal<N>, Decimal<N>) {
+        let mut q = *lhs;
+        let mut r = *lhs;
+        self.div_integer(&mut q, rhs);
+        self.rem(&mut r, rhs);
+        (q, r)
+    }
+
     /// Divides `lhs` by `rhs`, storing the integer part of the result in `lhs`.
     pub fn div_integer(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
         unsafe {
@@ -817,6 +2840,44 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Reports whether `a` and `b` are numerically equal.
+    ///
+    /// The result is the same as comparing the two with
+    /// [`partial_cmp`](Context::<Decimal<N>>::partial_cmp), so NaNs are never
+    /// equal to anything, `1.0` equals `1`, and `-0` equals `0`. Most unequal
+    /// pairs, however, are detected from their signs and exponents alone, and
+    /// pairs with identical exponents are compared digit by digit, without
+    /// invoking libdecnumber's general comparison.
+    pub fn equals(&mut self, a: &Decimal<N>, b: &Decimal<N>) -> bool {
+        if a.is_nan() || b.is_nan() {
+            // Defer to libdecnumber so that signaling NaNs raise the invalid
+            // operation condition.
+            return self.partial_cmp(a, b) == Some(Ordering::Equal);
+        }
+        if a.is_zero() || b.is_zero() {
+            return a.is_zero() && b.is_zero();
+        }
+        if a.is_negative() != b.is_negative() {
+            return false;
+        }
+        if a.is_infinite() || b.is_infinite() {
+            return a.is_infinite() && b.is_infinite();
+        }
+        // Coefficients have no leading zeros, so numbers with different
+        // adjusted exponents have different magnitudes.
+        let adjusted = |d: &Decimal<N>| i64::from(d.exponent) + i64::from(d.digits);
+        if adjusted(a) != adjusted(b) {
+            return false;
+        }
+        if a.exponent == b.exponent {
+            let units = usize::try_from(a.digits)
+                .unwrap()
+                .div_ceil(decnumber_sys::DECDPUN);
+            return a.lsu[..units] == b.lsu[..units];
+        }
+        self.partial_cmp(a, b) == Some(Ordering::Equal)
+    }
+
     /// Raises *e* to the power of `n`, storing the result in `n`.
     pub fn exp(&mut self, n: &mut Decimal<N>) {
         unsafe {
@@ -824,6 +2885,50 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Computes `e^n - 1`, storing the result in `n`.
+    ///
+    /// Computing `exp(n) - 1` directly cancels away most of the significant
+    /// digits when `n` is close to zero. Instead, when `|n| < 0.1`, this
+    /// method sums the Taylor series `n + n^2/2! + n^3/3! + ...` until further
+    /// terms no longer affect the sum, which remains accurate however small
+    /// `n` is. For larger magnitudes, `exp(n) - 1` is computed directly.
+    ///
+    /// In both cases, intermediate results are computed with the full
+    /// precision of `Decimal<N>` and the result is rounded to this context's
+    /// precision once at the end. The direct computation is subject to the
+    /// same restrictions on the context's exponent range as
+    /// [`exp`](Context::<Decimal<N>>::exp).
+    pub fn expm1(&mut self, n: &mut Decimal<N>) {
+        if n.is_nan() || n.is_zero() {
+            self.plus_nonzero(n);
+            return;
+        }
+        let mut cx = self.working_context();
+        let mut r = if Self::below_series_threshold(n) {
+            let mut sum = *n;
+            let mut term = *n;
+            for k in 2u32.. {
+                cx.mul(&mut term, n);
+                cx.div(&mut term, &Decimal::from(k));
+                let prev = sum;
+                cx.add(&mut sum, &term);
+                if sum == prev {
+                    break;
+                }
+            }
+            self.set_inexact();
+            sum
+        } else {
+            let mut r = *n;
+            cx.exp(&mut r);
+            cx.sub(&mut r, &Decimal::from(1));
+            self.set_status(self.status() | cx.status());
+            r
+        };
+        self.plus_nonzero(&mut r);
+        *n = r;
+    }
+
     /// Calculates the fused multiply-add `(x * y) + z` and stores the result
     /// in `x`.
     ///
@@ -841,6 +2946,107 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Calculates the fused multiply-add `(x * y) + z`, returning the result.
+    ///
+    /// This is equivalent to [`fma`](Context::<Decimal<N>>::fma), but leaves
+    /// `x` untouched. As with `fma`, the context's exponent range must be
+    /// within `[-999,999, 999,999]`; otherwise the result is NaN and the
+    /// invalid context flag is raised. See also [`Decimal::mul_add`], which
+    /// performs the operation in a context of its own.
+    pub fn fused_mul_add(&mut self, x: &Decimal<N>, y: &Decimal<N>, z: &Decimal<N>) -> Decimal<N> {
+        let mut r = *x;
+        self.fma(&mut r, y, z);
+        r
+    }
+
+    /// Decomposes `n` into a mantissa and a power of ten, returning
+    /// `(mantissa, exp)` such that `mantissa * 10^exp` is exactly `n`.
+    ///
+    /// This is the decimal analog of C's `frexp`. For finite, nonzero `n`,
+    /// the mantissa has the same sign and coefficient as `n` and satisfies
+    /// `1 <= |mantissa| < 10`, and `exp` is the adjusted exponent of `n`; for
+    /// example, `-1234.50` decomposes into `(-1.23450, 3)`. No rounding is
+    /// performed, and no conditions are raised.
+    ///
+    /// Zeros decompose into a zero of the same sign with an exponent of zero,
+    /// and an `exp` of zero. Infinities and NaNs are returned unchanged, with
+    /// an `exp` of zero.
+    pub fn frexp10(&mut self, n: &Decimal<N>) -> (Decimal<N>, i32) {
+        let mut mantissa = *n;
+        if n.is_special() {
+            return (mantissa, 0);
+        }
+        if n.is_zero() {
+            mantissa.exponent = 0;
+            return (mantissa, 0);
+        }
+        let digits = i32::try_from(n.digits).unwrap();
+        mantissa.exponent = 1 - digits;
+        (mantissa, n.exponent + digits - 1)
+    }
+
+    /// Reports the position of the first digit at which `a` and `b` differ,
+    /// or `None` if they are numerically equal.
+    ///
+    /// The coefficients are aligned by place value before comparison, so
+    /// trailing zeros do not count as differences (e.g., `1.5` and `1.50` are
+    /// equal). Positions are counted from zero, starting at the most
+    /// significant digit of whichever of `a` and `b` has the greater
+    /// magnitude.
+    ///
+    /// ```
+    /// use dec::{Context, Decimal};
+    /// let mut cx = Context::<Decimal<12>>::default();
+    /// let a = cx.parse("3.14159").unwrap();
+    /// let b = cx.parse("3.14169").unwrap();
+    /// assert_eq!(cx.first_differing_digit(&a, &b), Some(4));
+    /// ```
+    ///
+    /// This is intended as a diagnostic aid for investigating precision
+    /// problems. If `a` and `b` have different signs, or if either is not
+    /// finite, the values are considered to differ at position 0 unless they
+    /// are identical.
+    pub fn first_differing_digit(&mut self, a: &Decimal<N>, b: &Decimal<N>) -> Option<u32> {
+        if a.is_special() || b.is_special() {
+            return match self.total_cmp(a, b) {
+                Ordering::Equal => None,
+                _ => Some(0),
+            };
+        }
+        if a.is_zero() && b.is_zero() {
+            return None;
+        }
+        if a.is_negative() != b.is_negative() {
+            return Some(0);
+        }
+
+        // Returns the place value of the most significant digit of `d`, or
+        // `None` if `d` is zero.
+        let msd_place = |d: &Decimal<N>| {
+            if d.is_zero() {
+                None
+            } else {
+                Some(i64::from(d.exponent) + i64::from(d.digits) - 1)
+            }
+        };
+        // Returns the digit of `d` with place value `10^place`.
+        let digit_at = |d: &Decimal<N>, place: i64| {
+            let i = place - i64::from(d.exponent);
+            if i >= 0 && i < i64::from(d.digits) {
+                d.lsd(u32::try_from(i).unwrap())
+            } else {
+                0
+            }
+        };
+
+        let high = msd_place(a).max(msd_place(b)).unwrap();
+        let low = i64::from(a.exponent.min(b.exponent));
+        (low..=high)
+            .rev()
+            .position(|place| digit_at(a, place) != digit_at(b, place))
+            .map(|i| u32::try_from(i).unwrap())
+    }
+
     /// Constructs a number from an `i128`.
     ///
     /// Note that this function can return inexact results for numbers with more
@@ -977,45 +3183,445 @@ impl<const N: usize> Context<Decimal<N>> {
         Ok(d as usize)
     }
 
-    /// Attempts to convert `d` to `usize` or fails if not possible.
+    /// Attempts to convert `d` to `usize` or fails if not possible.
+    ///
+    /// Refer to the comments on [`Self::try_into_i32()`], which also apply to this
+    /// function.
+    #[cfg(target_pointer_width = "64")]
+    pub fn try_into_usize(&mut self, d: Decimal<N>) -> Result<usize, TryFromDecimalError> {
+        let d = self.try_into_u64(d)?;
+        Ok(d as usize)
+    }
+
+    /// Attempts to convert `d` to `u64` or fails if not possible.
+    ///
+    /// Refer to the comments on [`Self::try_into_i32()`], which also apply to this
+    /// function.
+    pub fn try_into_u64(&mut self, d: Decimal<N>) -> Result<u64, TryFromDecimalError> {
+        decnum_tryinto_primitive_uint!(u64, self, 20, d)
+    }
+
+    /// Attempts to convert `d` to `u128` or fails if not possible.
+    ///
+    /// Refer to the comments on [`Self::try_into_i32()`], which also apply to this
+    /// function.
+    pub fn try_into_u128(&mut self, d: Decimal<N>) -> Result<u128, TryFromDecimalError> {
+        decnum_tryinto_primitive_uint!(u128, self, 39, d)
+    }
+
+    /// Computes the greatest common divisor of `a` and `b`.
+    ///
+    /// The result is computed with the Euclidean algorithm using
+    /// [`rem`](Context::<Decimal<N>>::rem), and is always non-negative. The
+    /// greatest common divisor of zero and zero is zero.
+    ///
+    /// Both operands must be integers, though they need not have an exponent
+    /// of zero; for example, `1.00` and `1E+2` are accepted. If either operand
+    /// is not an integer or is a special value, the result is NaN and the
+    /// invalid operation flag is raised. Conditions raised by the underlying
+    /// remainder operations, such as division impossible for operands that
+    /// differ greatly in magnitude, are also raised on this context.
+    pub fn gcd(&mut self, a: &Decimal<N>, b: &Decimal<N>) -> Decimal<N> {
+        let (mut a, mut b) = match self.integer_operands(a, b) {
+            Some(operands) => operands,
+            None => return Decimal::nan(),
+        };
+        while !b.is_zero() {
+            let mut r = a;
+            self.rem(&mut r, &b);
+            if r.is_nan() {
+                return r;
+            }
+            a = b;
+            b = r;
+        }
+        self.integer_result(a)
+    }
+
+    /// Computes the square root of `x^2 + y^2` without undue overflow or
+    /// underflow.
+    ///
+    /// Squaring `x` and `y` directly can exceed the context's exponent range
+    /// even when the result is representable. Instead, both operands are
+    /// scaled by a power of ten that brings the larger magnitude into
+    /// [1, 10), the root is computed in a working context with the full
+    /// precision of `Decimal<N>`, and the result is scaled back and rounded to
+    /// this context. The result is within one unit in the last place of the
+    /// exact value, and is exact whenever the working computation is, e.g.
+    /// `hypot(3, 4)` is exactly `5`.
+    ///
+    /// Following IEEE 754, if either operand is infinite the result is
+    /// positive infinity, even if the other operand is a NaN. Otherwise, NaN
+    /// operands propagate as for [`add`](Context::<Decimal<N>>::add).
+    pub fn hypot(&mut self, x: &Decimal<N>, y: &Decimal<N>) -> Decimal<N> {
+        if x.is_infinite() || y.is_infinite() {
+            return Decimal::INFINITY;
+        }
+        if x.is_nan() || y.is_nan() {
+            let mut r = *x;
+            self.add(&mut r, y);
+            return r;
+        }
+        let (x, y) = (x.abs(), y.abs());
+        let (mut big, mut small) = if x >= y { (x, y) } else { (y, x) };
+        if big.is_zero() {
+            return Decimal::zero();
+        }
+        let adjusted = big.exponent + i32::try_from(big.digits).unwrap() - 1;
+        let mut cx = Context::<Decimal<N>>::default();
+        let shift = Decimal::<N>::from(-adjusted);
+        cx.scaleb(&mut big, &shift);
+        cx.scaleb(&mut small, &shift);
+        let mut r = big;
+        cx.mul(&mut r, &big);
+        let mut small_squared = small;
+        cx.mul(&mut small_squared, &small);
+        cx.add(&mut r, &small_squared);
+        cx.sqrt(&mut r);
+        // Round to this context's precision before restoring the scale, as
+        // `scaleb` only checks the exponent of the result.
+        self.plus(&mut r);
+        self.scaleb(&mut r, &Decimal::from(adjusted));
+        if cx.status().inexact() {
+            self.set_inexact();
+        }
+        r
+    }
+
+    /// Computes the IEEE 754 remainder of `lhs` divided by `rhs`, returning
+    /// the result.
+    ///
+    /// This is equivalent to [`rem_near`](Context::<Decimal<N>>::rem_near),
+    /// but leaves `lhs` untouched. The remainder is `lhs - rhs * q`, where `q`
+    /// is the integer nearest to `lhs / rhs`, with ties resolved to the even
+    /// integer, so for finite operands the result lies in
+    /// `[-|rhs|/2, |rhs|/2]`. By contrast, the result of
+    /// [`rem`](Context::<Decimal<N>>::rem) truncates the quotient and takes
+    /// the sign of `lhs`.
+    pub fn ieee_remainder(&mut self, lhs: &Decimal<N>, rhs: &Decimal<N>) -> Decimal<N> {
+        let mut r = *lhs;
+        self.rem_near(&mut r, rhs);
+        r
+    }
+
+    /// Computes the digitwise logical inversion of `n`, storing the result in
+    /// `n`.
+    pub fn invert(&mut self, n: &mut Decimal<N>) {
+        unsafe {
+            decnumber_sys::decNumberInvert(n.as_mut_ptr(), n.as_ptr(), &mut self.inner);
+        }
+    }
+
+    /// Reports whether `a` and `b` are close to one another, as with Python's
+    /// `math.isclose`.
+    ///
+    /// The numbers are close if `|a - b| <= max(rel_tol * max(|a|, |b|),
+    /// abs_tol)`. The relative tolerance `rel_tol` bounds the difference in
+    /// proportion to the larger magnitude, while the absolute tolerance
+    /// `abs_tol` is useful for comparisons near zero, where any relative
+    /// tolerance is vanishingly small.
+    ///
+    /// The computation uses the full precision of `Decimal<N>` and does not
+    /// affect this context's status, except as described below. Infinities are
+    /// close only to infinities of the same sign, and NaNs are never close to
+    /// anything, including themselves. If either tolerance is negative or a
+    /// NaN, the result is false and the invalid operation flag is raised.
+    pub fn is_close(
+        &mut self,
+        a: &Decimal<N>,
+        b: &Decimal<N>,
+        rel_tol: &Decimal<N>,
+        abs_tol: &Decimal<N>,
+    ) -> bool {
+        let valid_tolerance = |t: &Decimal<N>| !t.is_nan() && (!t.is_negative() || t.is_zero());
+        if !valid_tolerance(rel_tol) || !valid_tolerance(abs_tol) {
+            self.set_invalid_operation();
+            return false;
+        }
+        if a.is_nan() || b.is_nan() {
+            return false;
+        }
+        if a.is_infinite() || b.is_infinite() {
+            return a.is_infinite() && b.is_infinite() && a.is_negative() == b.is_negative();
+        }
+        let mut cx = self.working_context();
+        let mut diff = *a;
+        cx.sub(&mut diff, b);
+        let mut tol = *a;
+        cx.max_abs(&mut tol, b);
+        tol = tol.abs();
+        cx.mul(&mut tol, rel_tol);
+        cx.max(&mut tol, abs_tol);
+        diff.abs() <= tol
+    }
+
+    /// Computes the integer square root of `n`, i.e., the largest integer
+    /// whose square is no greater than `n`.
+    ///
+    /// Unlike rounding the result of [`sqrt`](Context::<Decimal<N>>::sqrt)
+    /// down, which can be off by one when the square root is rounded up to an
+    /// integer, the result is always exact: the square root is computed with
+    /// the full precision of `Decimal<N>`, truncated, and then corrected by
+    /// checking that `r^2 <= n < (r + 1)^2`. The check compares `r` with
+    /// `n / r` rather than squaring `r`, so it is exact even for roots with
+    /// more than half the precision's digits.
+    ///
+    /// Returns an error that carries the invalid operation condition if `n` is
+    /// negative, is not an integer, or is a special value. Returns an error
+    /// that carries the inexact and rounded conditions if the result cannot be
+    /// represented exactly with this context's precision. In either case, the
+    /// conditions are also raised on this context.
+    pub fn isqrt(&mut self, n: &Decimal<N>) -> Result<Decimal<N>, DecimalError> {
+        if !n.is_integer() || (n.is_negative() && !n.is_zero()) {
+            self.set_invalid_operation();
+            let mut status = Status::default();
+            status.set_invalid_operation();
+            return Err(DecimalError::new(status));
+        }
+        let mut cx = self.working_context();
+        cx.set_rounding(Rounding::Down);
+        let mut r = *n;
+        cx.sqrt(&mut r);
+        // Unlike quantizing, rounding to an integral value preserves a
+        // positive exponent, so a root like `1E+50` is not expanded to more
+        // digits than the precision allows.
+        cx.round_to_integral(&mut r);
+        loop {
+            let next = cx.next_integer(&r);
+            if !cx.square_at_most(&next, n) {
+                break;
+            }
+            r = next;
+        }
+        while !cx.square_at_most(&r, n) {
+            r = cx.prev_integer(&r);
+        }
+        // Now `r^2 <= n < next^2`. If `next` is `r + 1`, `r` is the integer
+        // square root. Otherwise `r` is at least 10^p, where p is the working
+        // precision, and `n`, having at most p digits, differs from `r^2` by
+        // either zero or more than `2r`, so the integer square root is either
+        // `r` or lies strictly between `r` and `next`, where it cannot be
+        // represented.
+        let next = cx.next_integer(&r);
+        let mut gap = next;
+        cx.sub(&mut gap, &r);
+        let exact = gap == Decimal::<N>::from(1) || {
+            cx.clear_status();
+            let mut q = *n;
+            cx.div(&mut q, &r);
+            q == r && !cx.status().inexact()
+        };
+        if !exact {
+            let mut status = Status::default();
+            status.set_inexact();
+            status.set_rounded();
+            self.set_status(self.status() | status);
+            return Err(DecimalError::new(status));
+        }
+        // Give roots that fit in the precision an exponent of zero, as for
+        // smaller roots.
+        let mut integral = r;
+        cx.quantize_to_places(&mut integral, 0);
+        if integral.is_finite() {
+            r = integral;
+        }
+        let (r, status) = self.isolate_status(|cx| {
+            let mut r = r;
+            cx.plus(&mut r);
+            r
+        });
+        Self::check_lossless(r, status)
+    }
+
+    /// Computes the least common multiple of `a` and `b`.
+    ///
+    /// The result is `|a * b| / gcd(a, b)`, computed as `|a| / gcd(a, b) * |b|`
+    /// to keep intermediate values small, and is always non-negative. The
+    /// least common multiple of zero and any integer is zero.
+    ///
+    /// The operands are subject to the same requirements as for
+    /// [`gcd`](Context::<Decimal<N>>::gcd). The final multiplication is
+    /// rounded to the context's precision, so a result with more digits than
+    /// the precision raises the inexact flag.
+    pub fn lcm(&mut self, a: &Decimal<N>, b: &Decimal<N>) -> Decimal<N> {
+        let (a, b) = match self.integer_operands(a, b) {
+            Some(operands) => operands,
+            None => return Decimal::nan(),
+        };
+        if a.is_zero() || b.is_zero() {
+            return Decimal::zero();
+        }
+        let gcd = self.gcd(&a, &b);
+        if gcd.is_nan() {
+            return gcd;
+        }
+        let mut r = a;
+        self.div(&mut r, &gcd);
+        self.mul(&mut r, &b);
+        self.integer_result(r)
+    }
+
+    // Returns the absolute values of `a` and `b` if both are integers, or
+    // raises the invalid operation flag and returns `None` otherwise.
+    fn integer_operands(
+        &mut self,
+        a: &Decimal<N>,
+        b: &Decimal<N>,
+    ) -> Option<(Decimal<N>, Decimal<N>)> {
+        if a.is_integer() && b.is_integer() {
+            Some((a.abs(), b.abs()))
+        } else {
+            self.set_invalid_operation();
+            None
+        }
+    }
+
+    // Gives the integer `n` an exponent of zero where the context's precision
+    // allows, so that, e.g., the greatest common divisor of `4.0` and `6` is
+    // `2` rather than `2.0`. Only zeros are discarded, so the rounded flag
+    // raised by the quantization is suppressed.
+    fn integer_result(&mut self, mut n: Decimal<N>) -> Decimal<N> {
+        let integral_digits = i64::from(n.digits) + i64::from(n.exponent);
+        if n.exponent < 0 || integral_digits <= i64::from(self.inner.digits) {
+            let status = self.status();
+            self.quantize_to_places(&mut n, 0);
+            self.set_status(status);
+        }
+        n
+    }
+
+    /// Computes the natural logarithm of `n`, storing the result in `n`.
+    pub fn ln(&mut self, n: &mut Decimal<N>) {
+        unsafe {
+            decnumber_sys::decNumberLn(n.as_mut_ptr(), n.as_ptr(), &mut self.inner);
+        }
+    }
+
+    /// Computes the natural logarithm of `1 + n`, storing the result in `n`.
     ///
-    /// Refer to the comments on [`Self::try_into_i32()`], which also apply to this
-    /// function.
-    #[cfg(target_pointer_width = "64")]
-    pub fn try_into_usize(&mut self, d: Decimal<N>) -> Result<usize, TryFromDecimalError> {
-        let d = self.try_into_u64(d)?;
-        Ok(d as usize)
+    /// Computing `ln(1 + n)` directly loses the digits of `n` that do not fit
+    /// in the context's precision once `1` is added to it. Instead, when
+    /// `|n| < 0.1`, this method sums the Taylor series
+    /// `n - n^2/2 + n^3/3 - ...` until further terms no longer affect the sum,
+    /// which remains accurate however small `n` is. For larger magnitudes,
+    /// `ln(1 + n)` is computed directly.
+    ///
+    /// In both cases, intermediate results are computed with the full
+    /// precision of `Decimal<N>` and the result is rounded to this context's
+    /// precision once at the end. The direct computation is subject to the
+    /// same restrictions on the context's exponent range as
+    /// [`ln`](Context::<Decimal<N>>::ln). As with `ln`, `n = -1` produces
+    /// negative infinity, and `n < -1` produces a NaN and raises the invalid
+    /// operation flag.
+    pub fn ln1p(&mut self, n: &mut Decimal<N>) {
+        if n.is_nan() || n.is_zero() {
+            self.plus_nonzero(n);
+            return;
+        }
+        let mut cx = self.working_context();
+        let mut r = if Self::below_series_threshold(n) {
+            let neg_n = -*n;
+            let mut sum = *n;
+            let mut power = neg_n;
+            for k in 2u32.. {
+                cx.mul(&mut power, &neg_n);
+                let mut term = power;
+                cx.div(&mut term, &Decimal::from(k));
+                let prev = sum;
+                cx.sub(&mut sum, &term);
+                if sum == prev {
+                    break;
+                }
+            }
+            self.set_inexact();
+            sum
+        } else {
+            let mut r = Decimal::<N>::from(1);
+            cx.add(&mut r, n);
+            cx.ln(&mut r);
+            self.set_status(self.status() | cx.status());
+            r
+        };
+        self.plus_nonzero(&mut r);
+        *n = r;
     }
 
-    /// Attempts to convert `d` to `u64` or fails if not possible.
-    ///
-    /// Refer to the comments on [`Self::try_into_i32()`], which also apply to this
-    /// function.
-    pub fn try_into_u64(&mut self, d: Decimal<N>) -> Result<u64, TryFromDecimalError> {
-        decnum_tryinto_primitive_uint!(u64, self, 20, d)
+    // Returns a copy of this context with a clear status and the full
+    // precision of `Decimal<N>`, for computing intermediate results.
+    fn working_context(&self) -> Context<Decimal<N>> {
+        let mut cx = self.clone();
+        cx.clear_status();
+        cx.set_precision(Decimal::<N>::MAX_PRECISION).unwrap();
+        cx
     }
 
-    /// Attempts to convert `d` to `u128` or fails if not possible.
-    ///
-    /// Refer to the comments on [`Self::try_into_i32()`], which also apply to this
-    /// function.
-    pub fn try_into_u128(&mut self, d: Decimal<N>) -> Result<u128, TryFromDecimalError> {
-        decnum_tryinto_primitive_uint!(u128, self, 39, d)
+    // Reports whether `|n| < 0.1`, below which `expm1` and `ln1p` use their
+    // series expansions.
+    fn below_series_threshold(n: &Decimal<N>) -> bool {
+        n.is_finite() && i64::from(n.exponent) + i64::from(n.digits) <= -1
     }
 
-    /// Computes the digitwise logical inversion of `n`, storing the result in
-    /// `n`.
-    pub fn invert(&mut self, n: &mut Decimal<N>) {
-        unsafe {
-            decnumber_sys::decNumberInvert(n.as_mut_ptr(), n.as_ptr(), &mut self.inner);
+    // Rounds `n` to this context's precision, leaving zeros untouched so that
+    // the sign of a negative zero is preserved.
+    fn plus_nonzero(&mut self, n: &mut Decimal<N>) {
+        if !n.is_zero() {
+            self.plus(n);
         }
     }
 
-    /// Computes the natural logarithm of `n`, storing the result in `n`.
-    pub fn ln(&mut self, n: &mut Decimal<N>) {
-        unsafe {
-            decnumber_sys::decNumberLn(n.as_mut_ptr(), n.as_ptr(), &mut self.inner);
+    // Reports whether `x^2 <= n` for a nonnegative integer `x` that is
+    // representable in this context, without computing `x^2`, which could
+    // need twice the context's precision. For such an `x`, `x <= n / x` holds
+    // exactly when it holds for `n / x` rounded toward negative infinity.
+    fn square_at_most(&mut self, x: &Decimal<N>, n: &Decimal<N>) -> bool {
+        if x.is_zero() {
+            return true;
         }
+        let rounding = self.rounding();
+        self.set_rounding(Rounding::Floor);
+        let mut q = *n;
+        self.div(&mut q, x);
+        self.set_rounding(rounding);
+        *x <= q
+    }
+
+    // Returns the smallest integer greater than the integer `x` that is
+    // representable in this context.
+    fn next_integer(&mut self, x: &Decimal<N>) -> Decimal<N> {
+        let rounding = self.rounding();
+        self.set_rounding(Rounding::Ceiling);
+        let mut next = *x;
+        self.next_plus(&mut next);
+        self.round_to_integral(&mut next);
+        self.set_rounding(rounding);
+        next
+    }
+
+    // Returns the largest integer less than the integer `x` that is
+    // representable in this context.
+    fn prev_integer(&mut self, x: &Decimal<N>) -> Decimal<N> {
+        let rounding = self.rounding();
+        self.set_rounding(Rounding::Floor);
+        let mut prev = *x;
+        self.next_minus(&mut prev);
+        self.round_to_integral(&mut prev);
+        self.set_rounding(rounding);
+        prev
+    }
+
+    // Raises the inexact and rounded flags.
+    fn set_inexact(&mut self) {
+        let mut status = self.status();
+        status.set_inexact();
+        status.set_rounded();
+        self.set_status(status);
+    }
+
+    // Raises the invalid operation flag.
+    fn set_invalid_operation(&mut self) {
+        let mut status = self.status();
+        status.set_invalid_operation();
+        self.set_status(status);
     }
 
     /// Computes the base-10 logarithm of `n`, storing the result in `n`.
@@ -1025,6 +3631,69 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Computes the base-2 logarithm of `n`, storing the result in `n`.
+    ///
+    /// This is equivalent to [`log_base`](Context::<Decimal<N>>::log_base)
+    /// with a base of 2. In particular, when `n` is an exact power of two,
+    /// like `1024` or `0.125`, the result is the exact integer exponent, and no
+    /// conditions are raised.
+    ///
+    /// Zero, negative, infinite, and NaN operands are handled as for
+    /// [`log10`](Context::<Decimal<N>>::log10), and the same restrictions on
+    /// the context's exponent range apply.
+    pub fn log2(&mut self, n: &mut Decimal<N>) {
+        *n = self.log_base(n, &Decimal::from(2));
+    }
+
+    /// Computes the logarithm of `n` to the given `base`.
+    ///
+    /// The logarithm is computed as `ln(n) / ln(base)` with the full precision
+    /// of `Decimal<N>`, and then rounded to this context's precision. When `n`
+    /// is an exact integral power of `base`, like `1000` in base `10` or
+    /// `0.125` in base `2`, the result is the exact integer exponent, and no
+    /// conditions are raised.
+    ///
+    /// The base must be finite, positive, and not equal to 1; otherwise the
+    /// result is NaN and the invalid operation flag is raised. Zero, negative,
+    /// infinite, and NaN values of `n` are handled as for
+    /// [`ln`](Context::<Decimal<N>>::ln), and the same restrictions on the
+    /// context's exponent range apply.
+    pub fn log_base(&mut self, n: &Decimal<N>, base: &Decimal<N>) -> Decimal<N> {
+        if n.is_nan() || base.is_nan() {
+            let mut r = *n;
+            self.add(&mut r, base);
+            return r;
+        }
+        if !base.is_finite() || base.is_zero() || base.is_negative() || *base == 1 {
+            self.set_invalid_operation();
+            return Decimal::nan();
+        }
+        let mut cx = self.working_context();
+        let mut r = *n;
+        cx.ln(&mut r);
+        let mut ln_base = *base;
+        cx.ln(&mut ln_base);
+        cx.div(&mut r, &ln_base);
+        if r.is_finite() {
+            let mut k = r;
+            cx.set_rounding(Rounding::HalfEven);
+            cx.quantize_to_places(&mut k, 0);
+            if let Ok(k) = i32::try_from(k) {
+                let (power, status) = cx.isolate_status(|cx| {
+                    let mut power = *base;
+                    cx.powi(&mut power, k);
+                    power
+                });
+                if !status.inexact() && power == *n {
+                    return Decimal::from(k);
+                }
+            }
+        }
+        self.set_status(self.status() | cx.status());
+        self.plus_nonzero(&mut r);
+        r
+    }
+
     /// Computes the adjusted exponent of the number, according to IEEE 754
     /// rules.
     pub fn logb(&mut self, n: &mut Decimal<N>) {
@@ -1048,6 +3717,19 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Replaces `n` with positive zero if it is negative, including if it is
+    /// negative zero. Non-negative numbers are left unchanged.
+    ///
+    /// If `n` is a NaN, it is left as a NaN; signaling NaNs are quieted and
+    /// raise the invalid operation flag, as with arithmetic operations.
+    pub fn max0(&mut self, n: &mut Decimal<N>) {
+        if n.is_nan() {
+            self.plus(n);
+        } else if n.is_negative() {
+            *n = Decimal::zero();
+        }
+    }
+
     /// Places whichever of `lhs` and `rhs` has the larger absolute value in
     /// `lhs`.
     pub fn max_abs(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
@@ -1061,6 +3743,46 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Returns the largest element of `values`, or `None` if `values` is empty.
+    ///
+    /// The elements are combined from first to last using
+    /// [`max`](Context::<Decimal<N>>::max), and so NaNs are handled in the same
+    /// way: a quiet NaN is ignored in favor of any number, while a signaling
+    /// NaN raises the invalid operation flag and produces a quiet NaN, which
+    /// is in turn ignored in favor of any later number. A slice with a single
+    /// element produces that element unchanged.
+    pub fn max_of(&mut self, values: &[Decimal<N>]) -> Option<Decimal<N>> {
+        let (first, rest) = values.split_first()?;
+        let mut result = *first;
+        for v in rest {
+            self.max(&mut result, v);
+        }
+        Some(result)
+    }
+
+    /// Computes the arithmetic mean of the elements of `iter`.
+    ///
+    /// The elements are summed, as with
+    /// [`sum`](Context::<Decimal<N>>::sum), while being counted, and the sum
+    /// is then divided by the count, subject to this context's precision and
+    /// rounding.
+    ///
+    /// The mean of an empty iterator is undefined, so an empty iterator
+    /// produces a NaN and raises the invalid operation flag.
+    pub fn mean<'a, I>(&mut self, iter: I) -> Decimal<N>
+    where
+        I: Iterator<Item = &'a Decimal<N>>,
+    {
+        let mut count = 0u64;
+        let mut sum = self.sum(iter.inspect(|_| count += 1));
+        if count == 0 {
+            self.set_invalid_operation();
+            return Decimal::nan();
+        }
+        self.div(&mut sum, &Decimal::from(count));
+        sum
+    }
+
     /// Places whichever of `lhs` and `rhs` is smaller in `lhs`.
     ///
     /// The comparison is performed using the same rules as for
@@ -1089,6 +3811,23 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Returns the smallest element of `values`, or `None` if `values` is empty.
+    ///
+    /// The elements are combined from first to last using
+    /// [`min`](Context::<Decimal<N>>::min), and so NaNs are handled in the same
+    /// way: a quiet NaN is ignored in favor of any number, while a signaling
+    /// NaN raises the invalid operation flag and produces a quiet NaN, which
+    /// is in turn ignored in favor of any later number. A slice with a single
+    /// element produces that element unchanged.
+    pub fn min_of(&mut self, values: &[Decimal<N>]) -> Option<Decimal<N>> {
+        let (first, rest) = values.split_first()?;
+        let mut result = *first;
+        for v in rest {
+            self.min(&mut result, v);
+        }
+        Some(result)
+    }
+
     /// Subtracts `n` from zero, storing the result in `n`.
     pub fn minus(&mut self, n: &mut Decimal<N>) {
         unsafe {
@@ -1152,6 +3891,42 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Computes the principal `n`th root of `x`, storing the result in `x`.
+    ///
+    /// For `n = 2`, this is [`sqrt`](Context::<Decimal<N>>::sqrt). Otherwise,
+    /// the root is computed as for [`cbrt`](Context::<Decimal<N>>::cbrt): it is
+    /// estimated with [`pow`](Context::<Decimal<N>>::pow) and refined with one
+    /// Newton–Raphson step at the full precision of `Decimal<N>`, and exact
+    /// roots produce exact results. The same accuracy guarantees apply.
+    ///
+    /// For odd `n`, the root of a negative number is the negated root of its
+    /// magnitude. For even `n`, a negative `x` produces a NaN and raises the
+    /// invalid operation flag. An `n` of zero or greater than 999,999, the
+    /// limit on the magnitude of exponents in libdecnumber's mathematical
+    /// functions, also produces a NaN and raises the invalid operation flag.
+    /// Zeros and infinities whose root is defined are left unchanged, and NaNs
+    /// propagate as for [`plus`](Context::<Decimal<N>>::plus).
+    pub fn nth_root(&mut self, x: &mut Decimal<N>, n: u32) {
+        if x.is_nan() {
+            self.plus(x);
+            return;
+        }
+        let even = n & 1 == 0;
+        if n == 0 || n > 999_999 || (even && x.is_negative() && !x.is_zero()) {
+            self.set_invalid_operation();
+            *x = Decimal::nan();
+            return;
+        }
+        if x.is_special() || x.is_zero() {
+            return;
+        }
+        match n {
+            1 => self.plus(x),
+            2 => self.sqrt(x),
+            _ => self.root_finite(x, n),
+        }
+    }
+
     /// Carries out the digitwise logical or of `lhs` and `rhs`, storing
     /// the result in `lhs`.
     pub fn or(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
@@ -1195,12 +3970,42 @@ impl<const N: usize> Context<Decimal<N>> {
     }
 
     /// Adds `n` to zero, storing the result in `n`.
+    ///
+    /// This rounds `n` to this context's precision and exponent range,
+    /// raising any resulting conditions, and quiets signaling NaNs. Use
+    /// [`copy`](Context::<Decimal<N>>::copy) to copy a number exactly.
     pub fn plus(&mut self, n: &mut Decimal<N>) {
         unsafe {
             decnumber_sys::decNumberPlus(n.as_mut_ptr(), n.as_ptr(), &mut self.inner);
         }
     }
 
+    /// Evaluates the polynomial with coefficients `coeffs` at `x`.
+    ///
+    /// The coefficients are in order of ascending power, so the result is
+    /// `coeffs[0] + coeffs[1]*x + coeffs[2]*x^2 + ...`. The polynomial is
+    /// evaluated with Horner's method, using [`fma`](Context::<Decimal<N>>::fma)
+    /// at each step so that each step is rounded only once.
+    ///
+    /// An empty slice of coefficients evaluates to zero, and a single
+    /// coefficient is returned unchanged.
+    ///
+    /// As with `fma`, libdecnumber restricts the exponent range of contexts
+    /// used for this operation to [-999,999, 999,999]. Using a context with a
+    /// wider range, like the default context, produces a NaN and sets the
+    /// invalid context flag.
+    pub fn poly_eval(&mut self, coeffs: &[Decimal<N>], x: &Decimal<N>) -> Decimal<N> {
+        let mut coeffs = coeffs.iter().rev();
+        let mut result = match coeffs.next() {
+            Some(c) => *c,
+            None => return Decimal::zero(),
+        };
+        for c in coeffs {
+            self.fma(&mut result, x, c);
+        }
+        result
+    }
+
     /// Raises `x` to the power of `y`, storing the result in `x`.
     pub fn pow(&mut self, x: &mut Decimal<N>, y: &Decimal<N>) {
         unsafe {
@@ -1208,6 +4013,59 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Raises `x` to the integral power `n`, storing the result in `x`.
+    ///
+    /// Unlike [`pow`](Context::<Decimal<N>>::pow), which uses logarithms for
+    /// some operands, this method uses exponentiation by squaring, so the
+    /// result is exact whenever it fits within this context's precision, as
+    /// in compound interest calculations like `1.05^30`.
+    ///
+    /// Intermediate products are computed with the full precision of
+    /// `Decimal<N>`, and the result is rounded to this context's precision
+    /// once at the end. For results that do not fit, however, the
+    /// intermediate products may themselves be rounded, and so the result may
+    /// differ from the correctly rounded power in the last place.
+    ///
+    /// A negative `n` produces the reciprocal of `x^-n`, computed by division.
+    /// `x^0` is `1` for any `x` that is not a NaN, including zero and
+    /// infinities. Raising zero to a negative power produces an infinity and
+    /// sets the division by zero flag. NaNs propagate as for
+    /// [`plus`](Context::<Decimal<N>>::plus).
+    pub fn powi(&mut self, x: &mut Decimal<N>, n: i32) {
+        if x.is_nan() {
+            self.plus(x);
+            return;
+        }
+        let mut cx = self.clone();
+        cx.clear_status();
+        cx.set_precision(Decimal::<N>::MAX_PRECISION).unwrap();
+        let mut result = Decimal::<N>::from(1);
+        let mut base = *x;
+        let mut e = n.unsigned_abs();
+        while e > 0 {
+            if e & 1 == 1 {
+                cx.mul(&mut result, &base);
+            }
+            e >>= 1;
+            if e > 0 {
+                let b = base;
+                cx.mul(&mut base, &b);
+            }
+        }
+        if n < 0 {
+            let mut reciprocal = Decimal::<N>::from(1);
+            cx.div(&mut reciprocal, &result);
+            result = reciprocal;
+        }
+        self.set_status(self.status() | cx.status());
+        // Adding zero would discard the sign of a negative zero, which
+        // needs no rounding anyway.
+        if !result.is_zero() {
+            self.plus(&mut result);
+        }
+        *x = result;
+    }
+
     /// Takes product of elements in `iter`.
     pub fn product<'a, I>(&mut self, iter: I) -> Decimal<N>
     where
@@ -1232,6 +4090,74 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Rounds or pads `n` so that it has exactly `places` digits after the
+    /// decimal point, storing the result in `n`.
+    ///
+    /// This is equivalent to [`Context::quantize`] with a right-hand side whose
+    /// exponent is `-places`. A negative `places` rounds to the left of the
+    /// decimal point, so `-2` rounds to a multiple of one hundred. As with
+    /// `quantize`, `invalid_operation` is raised and `n` is set to NaN if the
+    /// result would need more digits than the context's precision.
+    pub fn quantize_to_places(&mut self, n: &mut Decimal<N>, places: i32) {
+        let mut template = Decimal::<N>::ZERO;
+        template.set_exponent(places.saturating_neg());
+        self.quantize(n, &template);
+    }
+
+    /// Returns an iterator over `start`, `start + step`, `start + 2*step`, and
+    /// so on, up to but not including `end`.
+    ///
+    /// Each value is computed by adding `step` to the previous value in this
+    /// context, so the values are subject to its precision and rounding, and
+    /// any conditions raised by the additions accumulate in its status. If
+    /// `step` is negative, the values descend towards `end` instead.
+    ///
+    /// To guarantee termination, the iterator is empty and the invalid
+    /// operation flag is raised if `step` is zero or is not finite, or if
+    /// `start` or `end` is not finite. The iterator also ends early if
+    /// adding `step` no longer changes the value, as happens when `step` is
+    /// too small to register at the context's precision.
+    ///
+    /// ```
+    /// use dec::{Context, Decimal};
+    /// let mut cx = Context::<Decimal<12>>::default();
+    /// let values: Vec<_> = cx
+    ///     .range(Decimal::from(1), Decimal::from(2), "0.25".parse().unwrap())
+    ///     .map(|d| d.to_string())
+    ///     .collect();
+    /// assert_eq!(values, ["1", "1.25", "1.50", "1.75"]);
+    /// ```
+    pub fn range(
+        &mut self,
+        start: Decimal<N>,
+        end: Decimal<N>,
+        step: Decimal<N>,
+    ) -> impl Iterator<Item = Decimal<N>> + '_ {
+        let valid = step.is_finite() && !step.is_zero() && start.is_finite() && end.is_finite();
+        if !valid {
+            self.set_invalid_operation();
+        }
+        let descending = step.is_negative();
+        let mut next = if valid { Some(start) } else { None };
+        iter::from_fn(move || {
+            let current = next.take()?;
+            let in_range = if descending {
+                current > end
+            } else {
+                current < end
+            };
+            if !in_range {
+                return None;
+            }
+            let mut n = current;
+            self.add(&mut n, &step);
+            if n != current {
+                next = Some(n);
+            }
+            Some(current)
+        })
+    }
+
     /// Reduces `n`'s coefficient to its shortest possible form without
     /// changing the value of the result, storing the result in `n`.
     pub fn reduce(&mut self, n: &mut Decimal<N>) {
@@ -1252,6 +4178,28 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Computes the Euclidean remainder of `lhs` divided by `rhs`, storing the
+    /// result in `lhs`.
+    ///
+    /// Unlike [`rem`](Context::<Decimal<N>>::rem), whose result takes the sign
+    /// of `lhs`, the Euclidean remainder is never negative: for finite operands
+    /// it satisfies `0 <= rem_euclid(lhs, rhs) < |rhs|`, as with
+    /// [`i64::rem_euclid`]. A negative remainder from `rem` is corrected by
+    /// adding `|rhs|`; if that addition is inexact, the result may round to
+    /// `|rhs|` itself, as with [`f64::rem_euclid`].
+    ///
+    /// Special values and conditions are handled as for `rem`.
+    pub fn rem_euclid(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
+        self.rem(lhs, rhs);
+        if lhs.is_finite() && lhs.is_negative() {
+            if lhs.is_zero() {
+                *lhs = lhs.abs();
+            } else {
+                self.add(lhs, &rhs.abs());
+            }
+        }
+    }
+
     /// Like [`rem`](Context::<Decimal<N>>::rem), but uses the IEEE 754
     /// rules for remainder operations.
     pub fn rem_near(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
@@ -1265,6 +4213,30 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    // Rounds `n` to an integral value using the rounding mode in the context,
+    // storing the result in `n`.
+    fn round_to_integral(&mut self, n: &mut Decimal<N>) {
+        unsafe {
+            decnumber_sys::decNumberToIntegralExact(n.as_mut_ptr(), n.as_ptr(), &mut self.inner);
+        }
+    }
+
+    /// Rounds `n` to the context's precision using the rounding mode
+    /// `rounding`, returning the result.
+    ///
+    /// The context's own rounding mode is used only for the duration of the
+    /// call and is restored before returning, so this is equivalent to
+    /// [`plus`](Context::<Decimal<N>>::plus) under a temporarily modified
+    /// context, without the need to save and restore the mode manually.
+    pub fn round_with(&mut self, rounding: Rounding, n: &Decimal<N>) -> Decimal<N> {
+        let saved = self.rounding();
+        self.set_rounding(rounding);
+        let mut n = *n;
+        self.plus(&mut n);
+        self.set_rounding(saved);
+        n
+    }
+
     /// Rescales `n` to have an exponent of `exp`.
     pub fn rescale(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
         unsafe {
@@ -1295,6 +4267,17 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Shifts the digits of `n` by `amount` positions, storing the result in
+    /// `n`.
+    ///
+    /// This is equivalent to [`shift`](Context::<Decimal<N>>::shift) with an integer
+    /// amount. The amount must be within `[-precision, precision]`, where
+    /// `precision` is the context's precision; otherwise `n` is set to NaN and
+    /// the invalid operation flag is raised.
+    pub fn shift_by(&mut self, n: &mut Decimal<N>, amount: i32) {
+        self.shift(n, &Decimal::from(amount));
+    }
+
     /// Rotates the digits of `lhs` by `rhs`, storing the result in `lhs`.
     ///
     /// If `rhs` is positive, rotates to the left. If `rhs` is negative, rotates
@@ -1313,6 +4296,17 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Rotates the digits of `n` by `amount` positions, storing the result in
+    /// `n`.
+    ///
+    /// This is equivalent to [`rotate`](Context::<Decimal<N>>::rotate) with an integer
+    /// amount. The amount must be within `[-precision, precision]`, where
+    /// `precision` is the context's precision; otherwise `n` is set to NaN and
+    /// the invalid operation flag is raised.
+    pub fn rotate_by(&mut self, n: &mut Decimal<N>, amount: i32) {
+        self.rotate(n, &Decimal::from(amount));
+    }
+
     /// Multiplies `x` by 10<sup>`y`</sup>, storing the result in `x`.
     pub fn scaleb(&mut self, x: &mut Decimal<N>, y: &Decimal<N>) {
         unsafe {
@@ -1320,6 +4314,58 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Multiplies `n` by 10<sup>`e`</sup>, storing the result in `n`.
+    ///
+    /// The result, and any conditions raised, are the same as for
+    /// [`scaleb`](Context::<Decimal<N>>::scaleb) with a second operand of `e`,
+    /// except that `e` may be any `i32`, whereas `scaleb` rejects amounts
+    /// larger than twice the sum of the context's precision and maximum
+    /// exponent. A result too large to represent overflows, and a result too
+    /// small to represent underflows to a subnormal number or to zero, as the
+    /// context's rounding mode dictates.
+    ///
+    /// When the result is a normal number within the range of the context,
+    /// this is done by adjusting the exponent directly, without calling into
+    /// libdecnumber, so the coefficient is unchanged: `1.5` multiplied by
+    /// 10<sup>3</sup> is `1.5E+3`, not `1500`.
+    pub fn mul_pow10(&mut self, n: &mut Decimal<N>, e: i32) {
+        let exponent = i64::from(n.exponent) + i64::from(e);
+        if !n.is_special() && !n.is_zero() {
+            let precision = i64::try_from(self.precision()).unwrap();
+            let emax = i64::try_from(self.max_exponent()).unwrap();
+            let emin = i64::try_from(self.min_exponent()).unwrap();
+            let adjusted = exponent + i64::from(n.digits) - 1;
+            if i64::from(n.digits) <= precision
+                && adjusted >= emin
+                && exponent + precision - 1 <= emax
+            {
+                n.exponent = i32::try_from(exponent).unwrap();
+                return;
+            }
+        }
+        if !n.is_special() {
+            // Clamp the exponent to a range that libdecnumber can represent
+            // without changing the result. Every nonzero number whose
+            // exponent exceeds `MAX_EMAX` overflows, and every nonzero number
+            // whose coefficient lies more than one digit below the smallest
+            // subnormal exponent rounds alike.
+            let max = i64::try_from(Self::MAX_EMAX).unwrap() + 1;
+            let min = i64::try_from(Self::MIN_EMIN).unwrap()
+                - 2 * i64::try_from(Decimal::<N>::MAX_PRECISION).unwrap();
+            n.exponent = i32::try_from(exponent.clamp(min, max)).unwrap();
+        }
+        // Scaling by zero applies the context's limits to the result.
+        let zero = Decimal::<N>::zero();
+        unsafe {
+            decnumber_sys::decNumberScaleB(
+                n.as_mut_ptr(),
+                n.as_ptr(),
+                zero.as_ptr(),
+                &mut self.inner,
+            );
+        }
+    }
+
     /// Computes the square root of `n`, storing the result in `n`.
     pub fn sqrt(&mut self, n: &mut Decimal<N>) {
         unsafe {
@@ -1350,6 +4396,166 @@ impl<const N: usize> Context<Decimal<N>> {
         })
     }
 
+    /// Sums all elements of `iter` using compensated summation.
+    ///
+    /// Each addition is performed with Knuth's TwoSum algorithm, which
+    /// computes the rounding error of the addition with five further
+    /// additions and subtractions. Unlike the faster algorithms used in binary
+    /// floating point, TwoSum finds the error exactly in decimal arithmetic.
+    /// The errors are accumulated in a separate compensation term, which is
+    /// added to the total once at the end.
+    ///
+    /// When the context's precision is sufficient to represent every
+    /// intermediate sum exactly, no error is accumulated and the result is
+    /// identical to that of [`sum`](Context::<Decimal<N>>::sum). When it is
+    /// not, as when summing many values with a precision only a little larger
+    /// than that of the values themselves, the digits that `sum` would drop on
+    /// each addition are retained by the compensation term, so the result is
+    /// usually far closer to the exact sum. The compensation term is itself
+    /// subject to rounding, however, so the result is not guaranteed to be
+    /// correctly rounded. The compensation costs six additional operations per
+    /// element, so prefer `sum` when the precision is known to be sufficient.
+    ///
+    /// Once the running total becomes an infinity or a NaN, compensation
+    /// stops and the result is computed as by `sum`.
+    pub fn compensated_sum<'a, I>(&mut self, iter: I) -> Decimal<N>
+    where
+        I: Iterator<Item = &'a Decimal<N>>,
+    {
+        let mut sum = Decimal::<N>::zero();
+        let mut compensation = Decimal::<N>::zero();
+        for x in iter {
+            let mut t = sum;
+            self.add(&mut t, x);
+            if t.is_finite() {
+                // The portions of `t` contributed by each operand, and the
+                // digits of each operand that those portions lost.
+                let mut x_part = t;
+                self.sub(&mut x_part, &sum);
+                let mut sum_part = t;
+                self.sub(&mut sum_part, &x_part);
+                let mut sum_err = sum;
+                self.sub(&mut sum_err, &sum_part);
+                let mut x_err = *x;
+                self.sub(&mut x_err, &x_part);
+                self.add(&mut sum_err, &x_err);
+                self.add(&mut compensation, &sum_err);
+            }
+            sum = t;
+        }
+        if sum.is_finite() {
+            self.add(&mut sum, &compensation);
+        }
+        sum
+    }
+
+    /// Sums all elements of `iter` at the fixed scale `scale`, returning an
+    /// error if any element or the sum cannot be represented exactly at that
+    /// scale.
+    ///
+    /// Each element is first quantized to have exactly `scale` digits after
+    /// the decimal point, as by
+    /// [`quantize_to_places`](Context::<Decimal<N>>::quantize_to_places), and
+    /// the quantized elements are then summed. The sum therefore also has
+    /// exactly `scale` digits after the decimal point, as is required of
+    /// monetary amounts in accounting ledgers; for example, summing `1.5` and
+    /// `2.25` at a scale of 2 produces `3.75`, and summing `1` and `2` produces
+    /// `3.00`.
+    ///
+    /// Rather than rounding, an error is returned for the first element that
+    /// has nonzero digits beyond `scale` digits after the decimal point or is
+    /// not finite, or if quantizing an element or adding it to the sum would
+    /// need more digits than this context's precision provides. The error
+    /// carries the conditions raised by the failing operation, which are also
+    /// raised on this context; a NaN element raises the invalid operation
+    /// condition.
+    pub fn sum_at_scale<'a, I>(&mut self, iter: I, scale: i32) -> Result<Decimal<N>, DecimalError>
+    where
+        I: Iterator<Item = &'a Decimal<N>>,
+    {
+        let mut sum = Decimal::<N>::zero();
+        let ((), status) = self.isolate_status(|cx| cx.quantize_to_places(&mut sum, scale));
+        if status.invalid_operation() {
+            return Err(DecimalError::new(status));
+        }
+        for x in iter {
+            let (x, mut status) = self.isolate_status(|cx| {
+                let mut x = *x;
+                cx.quantize_to_places(&mut x, scale);
+                x
+            });
+            if x.is_nan() && !status.invalid_operation() {
+                status.set_invalid_operation();
+                self.set_invalid_operation();
+            }
+            let x = Self::check_lossless(x, status)?;
+            let (r, status) = self.isolate_status(|cx| {
+                let mut r = sum;
+                cx.add(&mut r, &x);
+                r
+            });
+            // Even if the rounded sum is exact, it would no longer have the
+            // requested scale.
+            if status.rounded() {
+                return Err(DecimalError::new(status));
+            }
+            sum = Self::check_lossless(r, status)?;
+        }
+        Ok(sum)
+    }
+
+    /// Sums all elements of `xs` using pairwise (cascade) summation.
+    ///
+    /// The slice is recursively split in half, each half is summed, and the
+    /// two partial sums are added together. When the context's precision is
+    /// insufficient to represent intermediate sums exactly, this bounds the
+    /// growth of the accumulated rounding error to O(log n) in the length of
+    /// `xs`, rather than the O(n) of [`sum`](Context::<Decimal<N>>::sum).
+    ///
+    /// Recursion stops once a subslice has eight or fewer elements, at which
+    /// point the subslice is summed sequentially.
+    pub fn sum_pairwise(&mut self, xs: &[Decimal<N>]) -> Decimal<N> {
+        if xs.len() <= PAIRWISE_SUM_THRESHOLD {
+            return self.sum(xs.iter());
+        }
+        let (lo, hi) = xs.split_at(xs.len() / 2);
+        let mut sum = self.sum_pairwise(lo);
+        let hi = self.sum_pairwise(hi);
+        self.add(&mut sum, &hi);
+        sum
+    }
+
+    /// Sums all elements of `xs` in a default `Decimal::<M>` context, returning
+    /// the wide result.
+    ///
+    /// The accumulation uses the full precision of `Decimal::<M>` and the
+    /// default context's exponent range, so the total does not overflow even
+    /// if it exceeds the range of this context. The sum is rounded using this
+    /// context's rounding mode, and any conditions raised while summing are
+    /// added to this context's status.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is less than `N`, as the elements could not then be
+    /// converted to `Decimal<M>` without rounding.
+    pub fn sum_widened<const M: usize>(&mut self, xs: &[Decimal<N>]) -> Decimal<M> {
+        assert!(
+            M >= N,
+            "cannot sum Decimal<{}> values in the narrower Decimal<{}>",
+            N,
+            M
+        );
+        let mut cx = Context::<Decimal<M>>::default();
+        cx.set_rounding(self.rounding());
+        let mut sum = Decimal::<M>::zero();
+        for x in xs {
+            let x = cx.to_width(*x);
+            cx.add(&mut sum, &x);
+        }
+        self.set_status(self.status() | cx.status());
+        sum
+    }
+
     /// Determines the ordering of `lhs` relative to `rhs`, using the
     /// total order predicate defined in IEEE 754-2008.
     ///
@@ -1376,6 +4582,268 @@ impl<const N: usize> Context<Decimal<N>> {
         }
     }
 
+    /// Like [`and`](Context::<Decimal<N>>::and), but returns the result rather
+    /// than storing it in `lhs`, or returns an error rather than a NaN if
+    /// either operand is not a valid logical operand.
+    ///
+    /// See [`Decimal::is_logical`] for the requirements on logical operands.
+    /// As with `and`, digits beyond the context's precision are discarded.
+    pub fn try_and(
+        &mut self,
+        lhs: &Decimal<N>,
+        rhs: &Decimal<N>,
+    ) -> Result<Decimal<N>, InvalidLogicalOperandError> {
+        self.try_logical_op(lhs, rhs, Self::and)
+    }
+
+    /// Like [`exp`](Context::<Decimal<N>>::exp), but returns the result rather
+    /// than storing it in `n`, or returns an error rather than a NaN if the
+    /// operation is invalid.
+    pub fn try_exp(&mut self, n: &Decimal<N>) -> Result<Decimal<N>, DecimalError> {
+        self.try_op(n, Context::exp)
+    }
+
+    /// Like [`invert`](Context::<Decimal<N>>::invert), but returns the result
+    /// rather than storing it in `n`, or returns an error rather than a NaN if
+    /// `n` is not a valid logical operand.
+    pub fn try_invert(&mut self, n: &Decimal<N>) -> Result<Decimal<N>, InvalidLogicalOperandError> {
+        n.check_logical()?;
+        let mut r = *n;
+        self.invert(&mut r);
+        Ok(r)
+    }
+
+    /// Like [`ln`](Context::<Decimal<N>>::ln), but returns the result rather
+    /// than storing it in `n`, or returns an error rather than a NaN if the
+    /// operation is invalid, e.g. if `n` is negative.
+    pub fn try_ln(&mut self, n: &Decimal<N>) -> Result<Decimal<N>, DecimalError> {
+        self.try_op(n, Context::ln)
+    }
+
+    /// Like [`log10`](Context::<Decimal<N>>::log10), but returns the result
+    /// rather than storing it in `n`, or returns an error rather than a NaN if
+    /// the operation is invalid, e.g. if `n` is negative.
+    pub fn try_log10(&mut self, n: &Decimal<N>) -> Result<Decimal<N>, DecimalError> {
+        self.try_op(n, Context::log10)
+    }
+
+    /// Like [`or`](Context::<Decimal<N>>::or), but returns the result rather
+    /// than storing it in `lhs`, or returns an error rather than a NaN if
+    /// either operand is not a valid logical operand.
+    pub fn try_or(
+        &mut self,
+        lhs: &Decimal<N>,
+        rhs: &Decimal<N>,
+    ) -> Result<Decimal<N>, InvalidLogicalOperandError> {
+        self.try_logical_op(lhs, rhs, Self::or)
+    }
+
+    /// Like [`pow`](Context::<Decimal<N>>::pow), but returns the result rather
+    /// than storing it in `x`, or returns an error rather than a NaN if the
+    /// operation is invalid, e.g. if `x` is negative and `y` is not an
+    /// integer.
+    pub fn try_pow(&mut self, x: &Decimal<N>, y: &Decimal<N>) -> Result<Decimal<N>, DecimalError> {
+        self.try_op(x, |cx, x| cx.pow(x, y))
+    }
+
+    /// Like [`product`](Context::<Decimal<N>>::product), but returns an error
+    /// if the product lost precision or was invalid.
+    ///
+    /// Specifically, an error is returned if any multiplication raised the
+    /// inexact, overflow, underflow, or invalid operation conditions, and the
+    /// error carries all of the conditions raised during the reduction. To
+    /// fail on a different set of conditions, call `product` via
+    /// [`checked_op`](Context::checked_op) with a [`StatusPolicy::ReturnErr`]
+    /// policy instead.
+    pub fn try_product<'a, I>(&mut self, iter: I) -> Result<Decimal<N>, DecimalError>
+    where
+        I: Iterator<Item = &'a Decimal<N>>,
+    {
+        let (product, status) = self.isolate_status(|cx| cx.product(iter));
+        Self::check_lossless(product, status)
+    }
+
+    /// Like [`sqrt`](Context::<Decimal<N>>::sqrt), but returns the result
+    /// rather than storing it in `n`, or returns an error rather than a NaN if
+    /// the operation is invalid, e.g. if `n` is negative.
+    pub fn try_sqrt(&mut self, n: &Decimal<N>) -> Result<Decimal<N>, DecimalError> {
+        self.try_op(n, Context::sqrt)
+    }
+
+    /// Like [`sum`](Context::<Decimal<N>>::sum), but returns an error if the
+    /// sum lost precision or was invalid.
+    ///
+    /// The conditions that produce an error are the same as for
+    /// [`try_product`](Context::<Decimal<N>>::try_product).
+    pub fn try_sum<'a, I>(&mut self, iter: I) -> Result<Decimal<N>, DecimalError>
+    where
+        I: Iterator<Item = &'a Decimal<N>>,
+    {
+        let (sum, status) = self.isolate_status(|cx| cx.sum(iter));
+        Self::check_lossless(sum, status)
+    }
+
+    /// Like [`xor`](Context::<Decimal<N>>::xor), but returns the result rather
+    /// than storing it in `lhs`, or returns an error rather than a NaN if
+    /// either operand is not a valid logical operand.
+    pub fn try_xor(
+        &mut self,
+        lhs: &Decimal<N>,
+        rhs: &Decimal<N>,
+    ) -> Result<Decimal<N>, InvalidLogicalOperandError> {
+        self.try_logical_op(lhs, rhs, Self::xor)
+    }
+
+    // Applies the logical operation `f` to a copy of `lhs` after checking that
+    // both operands are valid logical operands.
+    fn try_logical_op<F>(
+        &mut self,
+        lhs: &Decimal<N>,
+        rhs: &Decimal<N>,
+        f: F,
+    ) -> Result<Decimal<N>, InvalidLogicalOperandError>
+    where
+        F: FnOnce(&mut Self, &mut Decimal<N>, &Decimal<N>),
+    {
+        lhs.check_logical()?;
+        rhs.check_logical()?;
+        let mut r = *lhs;
+        f(self, &mut r, rhs);
+        Ok(r)
+    }
+
+    // Returns `r`, or an error if `status` indicates that `r` lost precision
+    // or is invalid.
+    fn check_lossless(r: Decimal<N>, status: Status) -> Result<Decimal<N>, DecimalError> {
+        if status.inexact() || status.overflow() || status.underflow() || status.invalid_operation()
+        {
+            Err(DecimalError::new(status))
+        } else {
+            Ok(r)
+        }
+    }
+
+    // Applies the in-place operation `f` to a copy of `n`, returning an error
+    // if `f` raised the invalid operation or invalid context conditions.
+    //
+    // The transcendental functions set invalid context, rather than invalid
+    // operation, when the context's exponent range is wider than libdecnumber
+    // permits for those functions.
+    fn try_op<F>(&mut self, n: &Decimal<N>, f: F) -> Result<Decimal<N>, DecimalError>
+    where
+        F: FnOnce(&mut Self, &mut Decimal<N>),
+    {
+        let (r, status) = self.isolate_status(|cx| {
+            let mut r = *n;
+            f(cx, &mut r);
+            r
+        });
+        if status.invalid_operation() || status.invalid_context() {
+            Err(DecimalError::new(status))
+        } else {
+            Ok(r)
+        }
+    }
+
+    /// Computes the number of representable values between `a` and `b` at
+    /// this context's precision, i.e., the number of times that
+    /// [`next_toward`](Context::<Decimal<N>>::next_toward) must be applied to
+    /// `a` to reach `b`.
+    ///
+    /// The distance is computed arithmetically rather than by stepping, so it
+    /// is cheap even for distant values. It accounts for the change in spacing
+    /// at each power of ten and for subnormal values. Zeros of either sign are
+    /// the same value, so the distance between `-0` and `0` is zero. Distances
+    /// too large to be represented exactly in a `Decimal<N>` are rounded. The
+    /// status of this context is not changed.
+    ///
+    /// Returns `None` if either operand is special, or if either operand is
+    /// not representable in this context, i.e., it has more significant digits
+    /// than the context's precision or lies outside its exponent range.
+    pub fn ulp_distance(&mut self, a: &Decimal<N>, b: &Decimal<N>) -> Option<Decimal<N>> {
+        let (a_neg, a_exp, a_steps) = self.ulp_index(a)?;
+        let (b_neg, b_exp, b_steps) = self.ulp_index(b)?;
+        let emin = i64::from(self.inner.emin);
+        let mut cx = Context::<Decimal<N>>::default();
+        // Each power of ten above the minimum exponent holds 9 * 10^(p-1)
+        // representable values.
+        let mut decade_steps = Decimal::<N>::from(9);
+        decade_steps.set_exponent(self.inner.digits - 1);
+        let (decades, steps) = if a_neg == b_neg {
+            let mut steps = b_steps;
+            cx.sub(&mut steps, &a_steps);
+            (i64::from(b_exp) - i64::from(a_exp), steps)
+        } else {
+            let mut steps = b_steps;
+            cx.add(&mut steps, &a_steps);
+            (i64::from(a_exp) + i64::from(b_exp) - 2 * emin, steps)
+        };
+        let mut d = Decimal::<N>::from(decades);
+        cx.mul(&mut d, &decade_steps);
+        cx.add(&mut d, &steps);
+        cx.abs(&mut d);
+        // The products and sums above inherit the exponents of the step
+        // counts, so give distances that fit in the precision an exponent of
+        // zero, which also turns equal operands' distance into `0`.
+        let mut integral = d;
+        cx.quantize_to_places(&mut integral, 0);
+        if integral.is_finite() {
+            d = integral;
+        }
+        Some(d)
+    }
+
+    // Locates `x` on the grid of values representable in this context,
+    // returning its sign, its adjusted exponent (clamped below to the minimum
+    // exponent), and its coefficient scaled to the context's precision.
+    //
+    // With these, the number of representable values between zero and `x` is
+    // `(exp - emin) * 9 * 10^(p-1) + steps`, where the second term counts the
+    // values within `x`'s power of ten.
+    fn ulp_index(&mut self, x: &Decimal<N>) -> Option<(bool, i32, Decimal<N>)> {
+        if x.is_special() {
+            return None;
+        }
+        let p = self.inner.digits;
+        let emin = self.inner.emin;
+        let mut steps = *x;
+        Context::<Decimal<N>>::default().reduce(&mut steps);
+        steps.bits &= !decnumber_sys::DECNEG;
+        if steps.is_zero() {
+            return Some((x.is_negative(), emin, Decimal::zero()));
+        }
+        let digits = i32::try_from(steps.digits()).unwrap();
+        let adjusted = steps.exponent + digits - 1;
+        if digits > p || adjusted > self.inner.emax {
+            return None;
+        }
+        if adjusted < emin {
+            let etiny = emin - p + 1;
+            if steps.exponent < etiny {
+                return None;
+            }
+            steps.exponent -= etiny;
+            Some((x.is_negative(), emin, steps))
+        } else {
+            steps.exponent = p - digits;
+            Some((x.is_negative(), adjusted, steps))
+        }
+    }
+
+    /// Returns a copy of `n` rescaled to have an exponent of `exp`.
+    ///
+    /// This is the usual way to fix a value to a given number of decimal
+    /// places: an `exp` of `-2`, for example, rounds `n` to hundredths using
+    /// the context's rounding mode. The `inexact` and `rounded` conditions are
+    /// raised if any non-zero digits are discarded. If the rescaled
+    /// coefficient would need more digits than the context's precision, the
+    /// result is NaN and `invalid_operation` is raised.
+    pub fn with_exponent(&mut self, n: &Decimal<N>, exp: i32) -> Decimal<N> {
+        let mut n = *n;
+        self.rescale(&mut n, &Decimal::from(exp));
+        n
+    }
+
     /// Carries out the digitwise logical xor of `lhs` and `rhs`, storing
     /// the result in `lhs`.
     pub fn xor(&mut self, lhs: &mut Decimal<N>, rhs: &Decimal<N>) {
@@ -1401,3 +4869,66 @@ impl<const N: usize> Context<Decimal<N>> {
         n
     }
 }
+
+const ONES_WORDS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS_WORDS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// The names of successive powers of 1000, starting at 1000^1.
+const SCALE_WORDS: [&str; 11] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+];
+
+// Spells out `n`, which must be in the range [1, 999], in English words.
+fn three_digits_to_words(n: usize) -> String {
+    debug_assert!(n > 0 && n < 1000);
+    let mut words = Vec::new();
+    let (hundreds, rest) = (n / 100, n % 100);
+    if hundreds > 0 {
+        words.push(format!("{} hundred", ONES_WORDS[hundreds]));
+    }
+    if rest >= 20 {
+        let (tens, ones) = (rest / 10, rest % 10);
+        if ones > 0 {
+            words.push(format!("{}-{}", TENS_WORDS[tens], ONES_WORDS[ones]));
+        } else {
+            words.push(String::from(TENS_WORDS[tens]));
+        }
+    } else if rest > 0 {
+        words.push(String::from(ONES_WORDS[rest]));
+    }
+    words.join(" ")
+}
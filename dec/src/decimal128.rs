@@ -13,21 +13,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::Ordering;
-use std::convert::TryInto;
-use std::ffi::{CStr, CString};
-use std::fmt;
-use std::iter::{Product, Sum};
-use std::marker::PhantomData;
-use std::mem::MaybeUninit;
-use std::ops::{
+use alloc::ffi::CString;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryInto;
+use core::ffi::CStr;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
-use std::str::FromStr;
+use core::str::FromStr;
 
 use libc::c_char;
 
-use crate::context::{Class, Context};
+use crate::context::{Class, Context, StatusPolicy};
 use crate::decimal::Decimal;
 use crate::decimal32::Decimal32;
 use crate::decimal64::Decimal64;
@@ -594,6 +597,11 @@ impl<'a> Product<&'a Decimal128> for Decimal128 {
     }
 }
 
+/// Constructs a context suitable for [`Decimal128`] values.
+///
+/// The context is initialized with `DEC_INIT_DECQUAD`, giving 34 digits of
+/// precision, an exponent range of -6143 to 6144, and half-even rounding, as
+/// required by IEEE 754 for the decimal128 format.
 impl Default for Context<Decimal128> {
     fn default() -> Context<Decimal128> {
         let mut ctx = MaybeUninit::<decnumber_sys::decContext>::uninit();
@@ -603,6 +611,7 @@ impl Default for Context<Decimal128> {
         };
         Context {
             inner: ctx,
+            status_policy: StatusPolicy::Ignore,
             _phantom: PhantomData,
         }
     }
@@ -614,14 +623,14 @@ impl Context<Decimal128> {
     where
         S: Into<Vec<u8>>,
     {
-        let c_string = CString::new(s).map_err(|_| ParseDecimalError)?;
+        let c_string = CString::new(s).map_err(|e| ParseDecimalError::diagnose(&e.into_vec()))?;
         let mut d = MaybeUninit::<decnumber_sys::decQuad>::uninit();
         let d = unsafe {
             decnumber_sys::decQuadFromString(d.as_mut_ptr(), c_string.as_ptr(), &mut self.inner);
             d.assume_init()
         };
         if (self.inner.status & decnumber_sys::DEC_Conversion_syntax) != 0 {
-            Err(ParseDecimalError)
+            Err(ParseDecimalError::diagnose(c_string.as_bytes()))
         } else {
             Ok(Decimal128 { inner: d })
         }
@@ -13,16 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::convert::TryFrom;
-use std::ffi::{CStr, CString};
-use std::fmt;
-use std::marker::PhantomData;
-use std::mem::MaybeUninit;
-use std::str::FromStr;
+use alloc::ffi::CString;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ffi::CStr;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::str::FromStr;
 
 use libc::c_char;
 
-use crate::context::Context;
+use crate::context::{Context, StatusPolicy};
 use crate::decimal::Decimal;
 use crate::decimal64::Decimal64;
 use crate::error::ParseDecimalError;
@@ -150,6 +152,15 @@ impl Decimal32 {
     pub fn exponent(&self) -> i32 {
         unsafe { decnumber_sys::decSingleGetExponent(&self.inner) }
     }
+
+    /// Reports whether the encoding of the number is canonical.
+    ///
+    /// A non-canonical encoding, which can only arise from bytes produced
+    /// outside of this library, uses one of the redundant densely packed
+    /// decimal declets or sets unused bits in an infinity or NaN.
+    pub fn is_canonical(&self) -> bool {
+        unsafe { decnumber_sys::decimal32IsCanonical(&self.inner) != 0 }
+    }
 }
 
 impl Default for Decimal32 {
@@ -191,15 +202,21 @@ impl FromStr for Decimal32 {
     }
 }
 
+/// Constructs a context suitable for [`Decimal32`] values.
+///
+/// The context is initialized with `DEC_INIT_DECSINGLE`, giving 7 digits of
+/// precision, an exponent range of -95 to 96, and half-even rounding, as
+/// required by IEEE 754 for the decimal32 format.
 impl Default for Context<Decimal32> {
     fn default() -> Context<Decimal32> {
         let mut ctx = MaybeUninit::<decnumber_sys::decContext>::uninit();
         let ctx = unsafe {
-            decnumber_sys::decContextDefault(ctx.as_mut_ptr(), decnumber_sys::DEC_INIT_DECDOUBLE);
+            decnumber_sys::decContextDefault(ctx.as_mut_ptr(), decnumber_sys::DEC_INIT_DECSINGLE);
             ctx.assume_init()
         };
         Context {
             inner: ctx,
+            status_policy: StatusPolicy::Ignore,
             _phantom: PhantomData,
         }
     }
@@ -211,14 +228,14 @@ impl Context<Decimal32> {
     where
         S: Into<Vec<u8>>,
     {
-        let c_string = CString::new(s).map_err(|_| ParseDecimalError)?;
+        let c_string = CString::new(s).map_err(|e| ParseDecimalError::diagnose(&e.into_vec()))?;
         let mut d = MaybeUninit::<decnumber_sys::decSingle>::uninit();
         let d = unsafe {
             decnumber_sys::decSingleFromString(d.as_mut_ptr(), c_string.as_ptr(), &mut self.inner);
             d.assume_init()
         };
         if (self.inner.status & decnumber_sys::DEC_Conversion_syntax) != 0 {
-            Err(ParseDecimalError)
+            Err(ParseDecimalError::diagnose(c_string.as_bytes()))
         } else {
             Ok(Decimal32 { inner: d })
         }
@@ -13,12 +13,160 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
+
+use crate::context::Status;
 
 /// An error indicating that a string is not a valid decimal number.
-#[derive(Debug, Eq, PartialEq)]
-pub struct ParseDecimalError;
+///
+/// The [`kind`](ParseDecimalError::kind) and
+/// [`position`](ParseDecimalError::position) methods describe what was wrong
+/// with the string and, where possible, the byte offset at which the problem
+/// was detected. The `Display` implementation does not include these details.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ParseDecimalError {
+    kind: ParseDecimalErrorKind,
+    position: Option<usize>,
+}
+
+/// The reason a string failed to parse as a decimal number.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParseDecimalErrorKind {
+    /// The string was empty.
+    Empty,
+    /// The string contained a character that is not valid at its position.
+    UnexpectedCharacter,
+    /// The coefficient contained more than one decimal point.
+    MultipleDecimalPoints,
+    /// A digit was required but not present, as in `-`, `.` or `1e`.
+    MissingDigit,
+    /// The string was otherwise invalid, e.g., because the payload of a NaN
+    /// was too long.
+    Invalid,
+}
+
+const SPECIAL_NAMES: [&[u8]; 4] = [b"inf", b"infinity", b"nan", b"snan"];
+
+impl ParseDecimalError {
+    /// Returns the reason the string failed to parse.
+    pub fn kind(&self) -> ParseDecimalErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the string at which the problem was
+    /// detected, if known.
+    ///
+    /// The offset is equal to the length of the string if the string ended
+    /// prematurely.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    pub(crate) fn new(kind: ParseDecimalErrorKind, position: Option<usize>) -> ParseDecimalError {
+        ParseDecimalError { kind, position }
+    }
+
+    /// Shifts the position of the error by `offset` bytes, for errors in a
+    /// substring of a larger string.
+    pub(crate) fn offset(self, offset: usize) -> ParseDecimalError {
+        ParseDecimalError {
+            kind: self.kind,
+            position: self.position.map(|p| p + offset),
+        }
+    }
+
+    /// Determines why libdecnumber rejected `s`.
+    ///
+    /// This is a lightweight re-scan of the syntax accepted by
+    /// `decNumberFromString`, run only after a conversion has failed.
+    pub(crate) fn diagnose(s: &[u8]) -> ParseDecimalError {
+        use ParseDecimalErrorKind::*;
+
+        let err = |kind, position| ParseDecimalError::new(kind, Some(position));
+        if s.is_empty() {
+            return ParseDecimalError::new(Empty, None);
+        }
+        let mut i = 0;
+        if matches!(s[0], b'+' | b'-') {
+            i += 1;
+        }
+        if matches!(s.get(i), Some(b) if b.is_ascii_alphabetic()) {
+            return ParseDecimalError::diagnose_special(s, i);
+        }
+        let mut digits = false;
+        let mut point = false;
+        while i < s.len() {
+            match s[i] {
+                b'0'..=b'9' => digits = true,
+                b'.' if point => return err(MultipleDecimalPoints, i),
+                b'.' => point = true,
+                b'e' | b'E' => break,
+                _ => return err(UnexpectedCharacter, i),
+            }
+            i += 1;
+        }
+        if !digits {
+            return err(MissingDigit, i);
+        }
+        if i < s.len() {
+            i += 1;
+            if matches!(s.get(i), Some(b'+' | b'-')) {
+                i += 1;
+            }
+            let start = i;
+            while i < s.len() {
+                if !s[i].is_ascii_digit() {
+                    return err(UnexpectedCharacter, i);
+                }
+                i += 1;
+            }
+            if i == start {
+                return err(MissingDigit, i);
+            }
+        }
+        ParseDecimalError::new(Invalid, None)
+    }
+
+    fn diagnose_special(s: &[u8], start: usize) -> ParseDecimalError {
+        use ParseDecimalErrorKind::*;
+
+        let word_len = s[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_alphabetic())
+            .count();
+        let word = &s[start..start + word_len];
+        if SPECIAL_NAMES.iter().any(|n| n.eq_ignore_ascii_case(word)) {
+            let mut i = start + word_len;
+            if word.eq_ignore_ascii_case(b"nan") || word.eq_ignore_ascii_case(b"snan") {
+                while matches!(s.get(i), Some(b) if b.is_ascii_digit()) {
+                    i += 1;
+                }
+            }
+            if i < s.len() {
+                ParseDecimalError::new(UnexpectedCharacter, Some(i))
+            } else {
+                ParseDecimalError::new(Invalid, None)
+            }
+        } else {
+            // Point at the first character that cannot begin any special
+            // value name.
+            let matched = SPECIAL_NAMES
+                .iter()
+                .map(|n| {
+                    n.iter()
+                        .zip(word)
+                        .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+                        .count()
+                })
+                .max()
+                .unwrap_or(0);
+            ParseDecimalError::new(UnexpectedCharacter, Some(start + matched))
+        }
+    }
+}
 
 impl fmt::Display for ParseDecimalError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -26,6 +174,7 @@ impl fmt::Display for ParseDecimalError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ParseDecimalError {}
 
 /// An error indicating that a precision is not valid for a given context.
@@ -38,6 +187,7 @@ impl fmt::Display for InvalidPrecisionError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for InvalidPrecisionError {}
 
 /// An error indicating that a minimum exponent or maximum exponent is not valid
@@ -51,8 +201,178 @@ impl fmt::Display for InvalidExponentError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for InvalidExponentError {}
 
+/// An error indicating that a conversion between decimal types could not be
+/// performed without losing information.
+#[derive(Debug, Eq, PartialEq)]
+pub struct InexactError;
+
+impl fmt::Display for InexactError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("decimal conversion is inexact")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InexactError {}
+
+/// An error indicating that a byte slice is not a valid tagged encoding of a
+/// decimal number, or encodes a number that does not fit in the target type.
+///
+/// See [`Decimal::from_tagged_bytes`](crate::Decimal::from_tagged_bytes).
+#[derive(Debug, Eq, PartialEq)]
+pub struct InvalidTaggedBytesError;
+
+impl fmt::Display for InvalidTaggedBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid tagged decimal bytes")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidTaggedBytesError {}
+
+/// An error indicating that a byte slice is not a valid canonical encoding of
+/// a decimal number, or encodes a number that does not fit in the target
+/// type.
+///
+/// See [`Decimal::from_canonical_bytes`](crate::Decimal::from_canonical_bytes).
+#[derive(Debug, Eq, PartialEq)]
+pub struct InvalidCanonicalBytesError;
+
+impl fmt::Display for InvalidCanonicalBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid canonical decimal bytes")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidCanonicalBytesError {}
+
+/// An error indicating that the raw parts of a decimal number are not
+/// internally consistent.
+///
+/// See [`Decimal::from_raw_parts`](crate::Decimal::from_raw_parts).
+#[derive(Debug, Eq, PartialEq)]
+pub struct InvalidRawPartsError {
+    reason: &'static str,
+}
+
+impl InvalidRawPartsError {
+    pub(crate) fn new(reason: &'static str) -> InvalidRawPartsError {
+        InvalidRawPartsError { reason }
+    }
+}
+
+impl fmt::Display for InvalidRawPartsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid decimal: {}", self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidRawPartsError {}
+
+/// An error indicating that a decimal is not a valid operand for a logical
+/// operation.
+///
+/// Logical operands must be finite, nonnegative integers with an exponent of
+/// zero whose digits are all either zero or one. See
+/// [`Decimal::is_logical`](crate::Decimal::is_logical).
+#[derive(Debug, Eq, PartialEq)]
+pub struct InvalidLogicalOperandError {
+    reason: &'static str,
+}
+
+impl InvalidLogicalOperandError {
+    pub(crate) fn new(reason: &'static str) -> InvalidLogicalOperandError {
+        InvalidLogicalOperandError { reason }
+    }
+}
+
+impl fmt::Display for InvalidLogicalOperandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid logical operand: {}", self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidLogicalOperandError {}
+
+/// An error indicating that a caller-provided buffer is too small to hold the
+/// output of an operation.
+///
+/// See [`Decimal::coefficient_digits_into`](crate::Decimal::coefficient_digits_into).
+#[derive(Debug, Eq, PartialEq)]
+pub struct BufferTooSmallError;
+
+impl fmt::Display for BufferTooSmallError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("buffer too small")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BufferTooSmallError {}
+
+/// An error indicating that a [`ContextBuilder`](crate::ContextBuilder) was
+/// configured with an invalid combination of properties.
+#[derive(Debug, Eq, PartialEq)]
+pub struct InvalidContextError;
+
+impl fmt::Display for InvalidContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid decimal context")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidContextError {}
+
+impl From<InvalidPrecisionError> for InvalidContextError {
+    fn from(_: InvalidPrecisionError) -> InvalidContextError {
+        InvalidContextError
+    }
+}
+
+impl From<InvalidExponentError> for InvalidContextError {
+    fn from(_: InvalidExponentError) -> InvalidContextError {
+        InvalidContextError
+    }
+}
+
+/// An error indicating that a decimal operation raised an exceptional
+/// condition.
+///
+/// The conditions that caused the error are available via
+/// [`DecimalError::status`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DecimalError {
+    status: Status,
+}
+
+impl DecimalError {
+    pub(crate) fn new(status: Status) -> DecimalError {
+        DecimalError { status }
+    }
+
+    /// Returns the exceptional conditions that caused the error.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("decimal operation raised an exceptional condition")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DecimalError {}
+
 /// An error indicating that a value cannot be cast to a primitive type.
 ///
 /// Causes for this failure include calling cast functions on values:
@@ -69,8 +389,26 @@ impl fmt::Display for TryFromDecimalError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for TryFromDecimalError {}
 
+/// An error indicating that a floating-point number cannot be converted to a
+/// decimal because it is a NaN or an infinity.
+///
+/// Finite floats always convert exactly, so this is the only way that the
+/// conversion can fail.
+#[derive(Debug, Eq, PartialEq)]
+pub struct TryFromFloatError;
+
+impl fmt::Display for TryFromFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("float is not finite")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryFromFloatError {}
+
 /// An error indicating that a value's coefficient cannot be cast to a primitive
 /// type.
 #[derive(Debug, Eq, PartialEq)]
@@ -82,4 +420,5 @@ impl fmt::Display for InvalidCoefficientError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for InvalidCoefficientError {}
@@ -0,0 +1,138 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generators of arbitrary decimals for property testing and fuzzing.
+//!
+//! Generated decimals are distributed as follows:
+//!
+//!   * About one in sixteen is a zero, one in sixteen is an infinity, and one
+//!     in sixteen is a NaN. Each has a randomly chosen sign.
+//!
+//!   * The remainder are finite and nonzero, with between one and `N * 3`
+//!     digits. About three quarters have exponents near zero, so that
+//!     arithmetic on them is interesting; the rest have adjusted exponents
+//!     anywhere in the range supported by the default context.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use crate::decimal::Decimal;
+
+// The range of adjusted exponents supported by the default context.
+const MAX_ADJUSTED_EXPONENT: i32 = 999_999_999;
+const MIN_ADJUSTED_EXPONENT: i32 = -999_999_999;
+
+// The largest magnitude of the adjusted exponents that are considered to be
+// near zero.
+const SMALL_ADJUSTED_EXPONENT: i32 = 20;
+
+/// Constructs a finite decimal from its sign, its coefficient as one digit per
+/// byte with no leading zeros, and its adjusted exponent.
+fn finite<const N: usize>(negative: bool, bcd: &[u8], adjusted_exponent: i32) -> Decimal<N> {
+    let digits = u32::try_from(bcd.len()).unwrap();
+    let mut d = Decimal::<N>::zero();
+    // `decNumberSetBCD` locates the most significant unit using the existing
+    // digit count, so it must be set first.
+    d.digits = digits;
+    unsafe {
+        decnumber_sys::decNumberSetBCD(d.as_mut_ptr(), bcd.as_ptr(), digits);
+    }
+    d.exponent = adjusted_exponent - (digits as i32 - 1);
+    if negative {
+        d.bits |= decnumber_sys::DECNEG;
+    }
+    d
+}
+
+/// Constructs a zero, infinity, or NaN with the specified sign.
+fn special<const N: usize>(kind: Special, negative: bool) -> Decimal<N> {
+    let mut d = match kind {
+        Special::Zero => Decimal::zero(),
+        Special::Infinity => Decimal::infinity(),
+        Special::Nan => Decimal::nan(),
+    };
+    if negative {
+        d.bits |= decnumber_sys::DECNEG;
+    }
+    d
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Special {
+    Zero,
+    Infinity,
+    Nan,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for Decimal<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Decimal<N>> {
+        let negative = u.arbitrary()?;
+        match u.int_in_range(0..=15u8)? {
+            0 => return Ok(special(Special::Zero, negative)),
+            1 => return Ok(special(Special::Infinity, negative)),
+            2 => return Ok(special(Special::Nan, negative)),
+            _ => (),
+        }
+        let digits = u.int_in_range(1..=N * decnumber_sys::DECDPUN)?;
+        let mut bcd = Vec::with_capacity(digits);
+        bcd.push(u.int_in_range(1..=9)?);
+        for _ in 1..digits {
+            bcd.push(u.int_in_range(0..=9)?);
+        }
+        let adjusted_exponent = if u.ratio(3, 4)? {
+            u.int_in_range(-SMALL_ADJUSTED_EXPONENT..=SMALL_ADJUSTED_EXPONENT)?
+        } else {
+            u.int_in_range(MIN_ADJUSTED_EXPONENT..=MAX_ADJUSTED_EXPONENT)?
+        };
+        Ok(finite(negative, &bcd, adjusted_exponent))
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<const N: usize> proptest::arbitrary::Arbitrary for Decimal<N> {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Decimal<N>>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        let special = (
+            prop_oneof![
+                Just(Special::Zero),
+                Just(Special::Infinity),
+                Just(Special::Nan)
+            ],
+            any::<bool>(),
+        )
+            .prop_map(|(kind, negative)| special(kind, negative));
+        let finite = (
+            any::<bool>(),
+            1..=9u8,
+            proptest::collection::vec(0..=9u8, 0..N * decnumber_sys::DECDPUN),
+            prop_oneof![
+                3 => -SMALL_ADJUSTED_EXPONENT..=SMALL_ADJUSTED_EXPONENT,
+                1 => MIN_ADJUSTED_EXPONENT..=MAX_ADJUSTED_EXPONENT,
+            ],
+        )
+            .prop_map(|(negative, msd, rest, adjusted_exponent)| {
+                let mut bcd = Vec::with_capacity(rest.len() + 1);
+                bcd.push(msd);
+                bcd.extend(rest);
+                finite(negative, &bcd, adjusted_exponent)
+            });
+        prop_oneof![3 => special, 13 => finite].boxed()
+    }
+}
@@ -69,6 +69,13 @@
 //!    A context configures the behavior of the various operations (e.g.,
 //!    rounding mode) and accumulates exceptional conditions (e.g., overflow).
 //!
+//! # `no_std` support
+//!
+//! The `std` feature is enabled by default. Disabling it builds dec without
+//! the standard library, in which case the crate depends only on `core` and
+//! `alloc`, so a global allocator is still required. The error types do not
+//! implement `std::error::Error` without the `std` feature.
+//!
 //! # Examples
 //!
 //! The following example demonstrates the basic usage of the library:
@@ -93,6 +100,9 @@
 
 #![deny(missing_debug_implementations, missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 mod context;
 #[macro_use]
@@ -102,14 +112,26 @@ mod decimal128;
 mod decimal32;
 mod decimal64;
 mod error;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod generate;
 mod ordered;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_coefficient_scale;
+mod stats;
 #[cfg(tests)]
 mod tests;
 
-pub use context::{Class, Context, Rounding, Status};
-pub use decimal::Decimal;
+pub use context::{Class, Context, ContextBuilder, Rounding, Status, StatusPolicy};
+pub use decimal::{Decimal, ValueKey};
 pub use decimal128::Decimal128;
 pub use decimal32::Decimal32;
 pub use decimal64::Decimal64;
-pub use error::{InvalidExponentError, InvalidPrecisionError, ParseDecimalError};
+pub use error::{
+    BufferTooSmallError, DecimalError, InexactError, InvalidCanonicalBytesError,
+    InvalidContextError, InvalidExponentError, InvalidLogicalOperandError, InvalidPrecisionError,
+    InvalidRawPartsError, InvalidTaggedBytesError, ParseDecimalError, ParseDecimalErrorKind,
+    TryFromFloatError,
+};
 pub use ordered::OrderedDecimal;
+pub use stats::RunningStats;
@@ -0,0 +1,111 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serializes a [`Decimal`] as a coefficient and a scale.
+//!
+//! This module is intended for use with serde's `with` attribute:
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! use dec::Decimal;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Row {
+//!     #[serde(with = "dec::serde_coefficient_scale")]
+//!     amount: Decimal<12>,
+//! }
+//! ```
+//!
+//! A decimal is represented as a struct with two fields: `coeff`, its
+//! coefficient as an `i128`, and `scale`, the negation of its exponent as an
+//! `i32`. The value of the decimal is `coeff * 10^-scale`, so `-1.50` is
+//! represented as `{ "coeff": -150, "scale": 2 }`. This is how many SQL
+//! drivers represent `NUMERIC` values. Note that the scale is negative for
+//! decimals with positive exponents, like `1E+3`.
+//!
+//! Unlike the default serialization of [`Decimal`], this representation does
+//! not depend on `N`, but it cannot represent every decimal. Serialization
+//! fails for infinities, NaNs, and decimals whose coefficients do not fit in
+//! an `i128`. The sign of a negative zero is not preserved.
+//!
+//! Deserialization fails if the coefficient has more digits than `Decimal<N>`
+//! can store, or if the exponent is out of range for a decimal with that
+//! coefficient.
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::context::Context;
+use crate::decimal::{exponent_in_range, Decimal};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "CoefficientScale")]
+struct CoefficientScale {
+    coeff: i128,
+    scale: i32,
+}
+
+/// Serializes `d` as a coefficient and a scale.
+///
+/// See the [module documentation](self) for details.
+pub fn serialize<S, const N: usize>(d: &Decimal<N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if d.is_special() {
+        return Err(S::Error::custom(
+            "cannot serialize an infinity or NaN as a coefficient and scale",
+        ));
+    }
+    let mut d = *d;
+    // The exponent of a finite decimal is never less than -1999999997, so
+    // its negation cannot overflow.
+    let scale = -d.exponent();
+    let coeff = d
+        .coefficient::<i128>()
+        .map_err(|_| S::Error::custom("decimal coefficient does not fit in an i128"))?;
+    CoefficientScale { coeff, scale }.serialize(serializer)
+}
+
+/// Deserializes a decimal from a coefficient and a scale.
+///
+/// See the [module documentation](self) for details.
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<Decimal<N>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let CoefficientScale { coeff, scale } = CoefficientScale::deserialize(deserializer)?;
+    let mut cx = Context::<Decimal<N>>::default();
+    let mut d = cx.from_i128(coeff);
+    if cx.status().inexact() {
+        return Err(D::Error::custom(format_args!(
+            "coefficient {} has more than {} digits",
+            coeff,
+            Decimal::<N>::MAX_PRECISION
+        )));
+    }
+    let exponent = scale
+        .checked_neg()
+        .filter(|exponent| exponent_in_range(d.digits(), *exponent))
+        .ok_or_else(|| {
+            D::Error::custom(format_args!(
+                "scale {} is out of range for coefficient {}",
+                scale, coeff
+            ))
+        })?;
+    d.set_exponent(exponent);
+    Ok(d)
+}
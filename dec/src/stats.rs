@@ -0,0 +1,161 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::context::Context;
+use crate::decimal::Decimal;
+
+/// Incrementally computed statistics over a stream of decimal numbers.
+///
+/// The count, sum, minimum, and maximum of the numbers are tracked directly,
+/// while the mean and variance are maintained using [Welford's online
+/// algorithm][welford], which avoids the loss of precision that results from
+/// subtracting the squared mean from the mean of the squares. No samples are
+/// retained.
+///
+/// The operations required to update and query the statistics are carried
+/// out in a caller-provided context, which determines their precision and
+/// rounding and accumulates any exceptional conditions.
+///
+/// [welford]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+///
+/// ```
+/// use dec::{Context, Decimal, RunningStats};
+/// let mut cx = Context::<Decimal<12>>::default();
+/// let mut stats = RunningStats::<12>::new();
+/// for i in &[2, 4, 4, 4, 5, 5, 7, 9] {
+///     stats.push(&mut cx, &Decimal::from(*i));
+/// }
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(Decimal::from(5)));
+/// assert_eq!(stats.variance(&mut cx), Some(Decimal::from(4)));
+/// assert_eq!(stats.stddev(&mut cx), Some(Decimal::from(2)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RunningStats<const N: usize> {
+    count: u64,
+    sum: Decimal<N>,
+    mean: Decimal<N>,
+    // The sum of the squared differences from the current mean.
+    m2: Decimal<N>,
+    min: Decimal<N>,
+    max: Decimal<N>,
+}
+
+impl<const N: usize> Default for RunningStats<N> {
+    fn default() -> RunningStats<N> {
+        RunningStats::new()
+    }
+}
+
+impl<const N: usize> RunningStats<N> {
+    /// Constructs a new `RunningStats` that has not observed any numbers.
+    pub fn new() -> RunningStats<N> {
+        RunningStats {
+            count: 0,
+            sum: Decimal::zero(),
+            mean: Decimal::zero(),
+            m2: Decimal::zero(),
+            min: Decimal::nan(),
+            max: Decimal::nan(),
+        }
+    }
+
+    /// Incorporates `x` into the statistics.
+    ///
+    /// NaNs propagate into the sum, mean, and variance, but are ignored by the
+    /// minimum and maximum, as with [`Context::<Decimal<N>>::min`] and
+    /// [`Context::<Decimal<N>>::max`].
+    pub fn push(&mut self, cx: &mut Context<Decimal<N>>, x: &Decimal<N>) {
+        self.count += 1;
+        cx.add(&mut self.sum, x);
+        if self.count == 1 {
+            self.min = *x;
+            self.max = *x;
+        } else {
+            cx.min(&mut self.min, x);
+            cx.max(&mut self.max, x);
+        }
+
+        // delta = x - mean
+        // mean += delta / count
+        // m2 += delta * (x - mean)
+        let mut delta = *x;
+        cx.sub(&mut delta, &self.mean);
+        let mut step = delta;
+        cx.div(&mut step, &Decimal::from(self.count));
+        cx.add(&mut self.mean, &step);
+        let mut delta2 = *x;
+        cx.sub(&mut delta2, &self.mean);
+        cx.mul(&mut delta, &delta2);
+        cx.add(&mut self.m2, &delta);
+    }
+
+    /// Returns the number of numbers observed.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the sum of the observed numbers.
+    ///
+    /// The sum of zero numbers is zero.
+    pub fn sum(&self) -> Decimal<N> {
+        self.sum
+    }
+
+    /// Returns the mean of the observed numbers, or `None` if no numbers have
+    /// been observed.
+    pub fn mean(&self) -> Option<Decimal<N>> {
+        self.nonempty().map(|_| self.mean)
+    }
+
+    /// Returns the population variance of the observed numbers, or `None` if
+    /// no numbers have been observed.
+    pub fn variance(&self, cx: &mut Context<Decimal<N>>) -> Option<Decimal<N>> {
+        self.nonempty().map(|count| {
+            let mut variance = self.m2;
+            cx.div(&mut variance, &Decimal::from(count));
+            variance
+        })
+    }
+
+    /// Returns the population standard deviation of the observed numbers, or
+    /// `None` if no numbers have been observed.
+    pub fn stddev(&self, cx: &mut Context<Decimal<N>>) -> Option<Decimal<N>> {
+        self.variance(cx).map(|mut stddev| {
+            cx.sqrt(&mut stddev);
+            stddev
+        })
+    }
+
+    /// Returns the smallest observed number, or `None` if no numbers have been
+    /// observed.
+    pub fn min(&self) -> Option<Decimal<N>> {
+        self.nonempty().map(|_| self.min)
+    }
+
+    /// Returns the largest observed number, or `None` if no numbers have been
+    /// observed.
+    pub fn max(&self) -> Option<Decimal<N>> {
+        self.nonempty().map(|_| self.max)
+    }
+
+    fn nonempty(&self) -> Option<u64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.count)
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arbitrary::{Arbitrary, Unstructured};
+use rand::{Rng, SeedableRng};
+
+use dec::{Context, Decimal};
+
+#[test]
+fn test_arbitrary() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let (mut specials, mut small, mut large) = (0, 0, 0);
+    for _ in 0..1000 {
+        let bytes: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+        let d = Decimal::<N>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert!(d.digits() as usize <= N * 3);
+        if d.is_special() || d.is_zero() {
+            specials += 1;
+            continue;
+        }
+        // Generated decimals are valid, so they survive a round trip through
+        // their string representation.
+        let s = d.to_string();
+        let parsed = cx.parse(s.as_str()).unwrap();
+        assert_eq!(parsed.to_string(), s);
+        assert_eq!(parsed.to_raw_parts().0, d.digits());
+        if (d.exponent() + d.digits() as i32 - 1).abs() <= 20 {
+            small += 1;
+        } else {
+            large += 1;
+        }
+    }
+    assert!(!cx.status().any());
+    assert!(specials > 0);
+    assert!(small > large);
+    assert!(large > 0);
+
+    // Exhausted input still produces a decimal.
+    Decimal::<N>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+}
@@ -23,7 +23,12 @@ use std::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
-use dec::{Context, Decimal, Decimal128, Decimal32, Decimal64, OrderedDecimal, Status};
+use dec::{
+    BufferTooSmallError, Context, ContextBuilder, Decimal, Decimal128, Decimal32, Decimal64,
+    InexactError, InvalidCanonicalBytesError, InvalidContextError, InvalidLogicalOperandError,
+    InvalidTaggedBytesError, OrderedDecimal, ParseDecimalErrorKind, Rounding, RunningStats, Status,
+    StatusPolicy, TryFromFloatError,
+};
 
 #[derive(Default)]
 struct ValidatingHasher {
@@ -1757,3 +1762,3561 @@ fn test_decnum_coefficient() {
     inner(&min_i128);
     inner(&max_i128);
 }
+
+#[test]
+fn test_sum_pairwise() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    assert_eq!(cx.sum_pairwise(&[]), Decimal::<N>::zero());
+    let xs: Vec<Decimal<N>> = (1..=20).map(Decimal::<N>::from).collect();
+    assert_eq!(cx.sum_pairwise(&xs), Decimal::<N>::from(210));
+    assert!(!cx.status().any());
+
+    // With a limited precision, naive summation of many similar-magnitude
+    // values drops low-order digits on nearly every addition once the
+    // running total grows; pairwise summation only adds similarly-sized
+    // partial sums and so loses far less.
+    let xs = vec![cx.parse("1.2345678").unwrap(); 10_000];
+    let exact = cx.sum(xs.iter());
+    assert_eq!(exact.to_string(), "12345.6780000");
+
+    let mut cx = Context::<Decimal<N>>::default();
+    cx.set_precision(8).unwrap();
+    let mut naive = cx.sum(xs.iter());
+    let mut pairwise = cx.sum_pairwise(&xs);
+
+    let mut cx = Context::<Decimal<N>>::default();
+    cx.sub(&mut naive, &exact);
+    cx.abs(&mut naive);
+    cx.sub(&mut pairwise, &exact);
+    cx.abs(&mut pairwise);
+    assert!(pairwise < naive);
+}
+
+#[test]
+fn test_context_builder() {
+    const N: usize = 12;
+    let cx = ContextBuilder::<Decimal<N>>::new().build().unwrap();
+    let default = Context::<Decimal<N>>::default();
+    assert_eq!(cx.precision(), default.precision());
+    assert_eq!(cx.rounding(), default.rounding());
+    assert_eq!(cx.max_exponent(), default.max_exponent());
+    assert_eq!(cx.min_exponent(), default.min_exponent());
+    assert_eq!(cx.clamp(), default.clamp());
+
+    let cx = ContextBuilder::<Decimal<N>>::new()
+        .precision(7)
+        .rounding(Rounding::Ceiling)
+        .max_exponent(96)
+        .min_exponent(-95)
+        .clamp(true)
+        .build()
+        .unwrap();
+    assert_eq!(cx.precision(), 7);
+    assert_eq!(cx.rounding(), Rounding::Ceiling);
+    assert_eq!(cx.max_exponent(), 96);
+    assert_eq!(cx.min_exponent(), -95);
+    assert!(cx.clamp());
+
+    for builder in &[
+        ContextBuilder::<Decimal<N>>::new().precision(0),
+        ContextBuilder::<Decimal<N>>::new().precision(N * 3 + 1),
+        ContextBuilder::<Decimal<N>>::new().max_exponent(-1),
+        ContextBuilder::<Decimal<N>>::new().min_exponent(1),
+    ] {
+        assert_eq!(builder.clone().build().unwrap_err(), InvalidContextError);
+    }
+}
+
+#[test]
+fn test_amount_to_words() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    for (input, expected) in &[
+        ("123.45", "one hundred twenty-three dollars and 45/100"),
+        ("1000.00", "one thousand dollars and 00/100"),
+        ("0.05", "zero dollars and 05/100"),
+        ("1", "one dollar and 00/100"),
+        ("0", "zero dollars and 00/100"),
+        ("19.990", "nineteen dollars and 99/100"),
+        (
+            "1234567.89",
+            "one million two hundred thirty-four thousand five hundred sixty-seven dollars and 89/100",
+        ),
+        ("3E+6", "three million dollars and 00/100"),
+    ] {
+        let n = cx.parse(*input).unwrap();
+        assert_eq!(cx.amount_to_words(&n).unwrap(), *expected);
+    }
+
+    for input in &[
+        "-1.00", "-0", "-0.00", "NaN", "Infinity", "1E+36", "123.456", "19.999", "0.001",
+    ] {
+        let mut cx = Context::<Decimal<N>>::default();
+        let n = cx.parse(*input).unwrap();
+        let err = cx.amount_to_words(&n).unwrap_err();
+        assert!(err.status().invalid_operation());
+        assert!(cx.status().invalid_operation());
+    }
+}
+
+#[test]
+fn test_checked_fma() {
+    const N: usize = 12;
+    let new_cx = || {
+        ContextBuilder::<Decimal<N>>::new()
+            .max_exponent(999_999)
+            .min_exponent(-999_999)
+            .build()
+            .unwrap()
+    };
+    let mut cx = new_cx();
+    let x = cx.parse("1.5").unwrap();
+    let y = cx.parse("4").unwrap();
+    let z = cx.parse("0.25").unwrap();
+    assert_eq!(cx.checked_fma(&x, &y, &z).unwrap().to_string(), "6.25");
+    assert!(!cx.status().any());
+
+    let x = cx.parse("9E+999999").unwrap();
+    let y = cx.parse("10").unwrap();
+    let err = cx.checked_fma(&x, &y, &z).unwrap_err();
+    assert!(err.status().overflow());
+    assert!(cx.status().overflow());
+
+    let mut cx = new_cx();
+    let x = Decimal::<N>::infinity();
+    let y = Decimal::<N>::zero();
+    let err = cx.checked_fma(&x, &y, &z).unwrap_err();
+    assert!(err.status().invalid_operation());
+
+    // The default context's exponent range is too wide for libdecnumber's
+    // math functions.
+    let mut cx = Context::<Decimal<N>>::default();
+    let x = Decimal::<N>::from(2);
+    let err = cx.checked_fma(&x, &x, &x).unwrap_err();
+    assert!(err.status().invalid_context());
+}
+
+#[test]
+fn test_decimal_is_integer() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    for (input, expected) in &[
+        ("0", true),
+        ("-0", true),
+        ("0.000", true),
+        ("0E+5", true),
+        ("1", true),
+        ("-17", true),
+        ("1.00", true),
+        ("1E+2", true),
+        ("12345678900000.0000", true),
+        ("-100.0", true),
+        ("1.5", false),
+        ("-0.001", false),
+        ("1000.0001", false),
+        ("1E-2", false),
+        ("1234567890123456789.0000000001", false),
+        ("Infinity", false),
+        ("-Infinity", false),
+        ("NaN", false),
+        ("sNaN", false),
+    ] {
+        let d = cx.parse(*input).unwrap();
+        assert_eq!(d.is_integer(), *expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_first_differing_digit() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    for (a, b, expected) in &[
+        ("1.2345678", "1.2346678", Some(4)),
+        ("123456789", "123466789", Some(4)),
+        ("1.2345678", "1.2345678", None),
+        ("1.5", "1.50", None),
+        ("1E+3", "1000.000", None),
+        ("0", "-0.00", None),
+        ("1.5", "1.51", Some(2)),
+        ("99", "100", Some(0)),
+        ("0.001", "0", Some(0)),
+        ("0.0012", "0.0013", Some(1)),
+        ("1", "-1", Some(0)),
+        ("NaN", "NaN", None),
+        ("Infinity", "Infinity", None),
+        ("Infinity", "-Infinity", Some(0)),
+        ("NaN", "1", Some(0)),
+    ] {
+        let a = cx.parse(*a).unwrap();
+        let b = cx.parse(*b).unwrap();
+        assert_eq!(cx.first_differing_digit(&a, &b), *expected, "{} {}", a, b);
+        assert_eq!(cx.first_differing_digit(&b, &a), *expected, "{} {}", b, a);
+    }
+}
+
+#[test]
+fn test_decimal_split() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    for (input, int, fract) in &[
+        ("12.345", "12", "0.345"),
+        ("-12.345", "-12", "-0.345"),
+        ("0.5", "0", "0.5"),
+        ("-0.5", "-0", "-0.5"),
+        ("7", "7", "0"),
+        ("-7", "-7", "-0"),
+        ("1.000", "1", "0.000"),
+        ("-1.000", "-1", "-0.000"),
+        ("1E+5", "1E+5", "0E+5"),
+        ("123456789.987654321", "123456789", "0.987654321"),
+        ("Infinity", "Infinity", "NaN"),
+        ("-Infinity", "-Infinity", "NaN"),
+        ("NaN", "NaN", "NaN"),
+    ] {
+        let d = cx.parse(*input).unwrap();
+        let (i, f) = d.split();
+        assert_eq!(i.to_string(), *int, "{}", input);
+        assert_eq!(f.to_string(), *fract, "{}", input);
+        if d.is_finite() {
+            let mut sum = i;
+            cx.add(&mut sum, &f);
+            assert_eq!(sum, d);
+        }
+    }
+}
+
+#[test]
+fn test_checked_rem() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    let mut lhs = cx.parse("10.5").unwrap();
+    let rhs = cx.parse("3").unwrap();
+    cx.checked_rem(&mut lhs, &rhs).unwrap();
+    assert_eq!(lhs.to_string(), "1.5");
+    assert!(!cx.status().any());
+
+    let mut lhs = cx.parse("10").unwrap();
+    let err = cx.checked_rem(&mut lhs, &Decimal::<N>::zero()).unwrap_err();
+    assert!(err.status().invalid_operation());
+    assert!(!err.status().division_impossible());
+    assert!(lhs.is_nan());
+
+    let mut cx = Context::<Decimal<N>>::default();
+    let mut lhs = cx.parse("1E+40").unwrap();
+    let err = cx.checked_rem(&mut lhs, &rhs).unwrap_err();
+    assert!(err.status().division_impossible());
+    assert!(lhs.is_nan());
+    assert!(cx.status().division_impossible());
+}
+
+#[test]
+fn test_try_transcendental() {
+    const N: usize = 12;
+    let mut cx = ContextBuilder::<Decimal<N>>::new()
+        .precision(10)
+        .max_exponent(999_999)
+        .min_exponent(-999_999)
+        .build()
+        .unwrap();
+    let two = Decimal::<N>::from(2);
+    let hundred = Decimal::<N>::from(100);
+    let neg = Decimal::<N>::from(-4);
+
+    assert_eq!(cx.try_exp(&Decimal::<N>::zero()).unwrap().to_string(), "1");
+    assert_eq!(cx.try_ln(&two).unwrap().to_string(), "0.6931471806");
+    assert_eq!(cx.try_log10(&hundred).unwrap().to_string(), "2");
+    assert_eq!(cx.try_sqrt(&hundred).unwrap().to_string(), "10");
+    assert_eq!(
+        cx.try_pow(&two, &Decimal::<N>::from(10))
+            .unwrap()
+            .to_string(),
+        "1024"
+    );
+    assert!(!cx.status().invalid_operation());
+
+    let half = cx.parse("0.5").unwrap();
+    for result in &[
+        cx.try_ln(&neg),
+        cx.try_log10(&neg),
+        cx.try_sqrt(&neg),
+        cx.try_pow(&neg, &half),
+    ] {
+        let err = result.unwrap_err();
+        assert!(err.status().invalid_operation());
+    }
+    assert!(cx.status().invalid_operation());
+
+    // The default context's exponent range is too wide for libdecnumber's
+    // transcendental functions.
+    let mut cx = Context::<Decimal<N>>::default();
+    let err = cx.try_ln(&two).unwrap_err();
+    assert!(err.status().invalid_context());
+}
+
+#[test]
+fn test_sum_widened() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .max_exponent(5)
+        .min_exponent(-5)
+        .build()
+        .unwrap();
+    let big = cx.parse("999999").unwrap();
+    let xs = [big, big, big];
+
+    let narrow = cx.sum(xs.iter());
+    assert!(narrow.is_infinite());
+    assert!(cx.status().overflow());
+
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .max_exponent(5)
+        .min_exponent(-5)
+        .build()
+        .unwrap();
+    let wide: Decimal<14> = cx.sum_widened(&xs);
+    assert!(wide.is_finite());
+    assert_eq!(wide.to_string(), "2999997");
+    assert!(!cx.status().overflow());
+    assert!(!cx.status().inexact());
+
+    let empty: Decimal<14> = cx.sum_widened::<14>(&[]);
+    assert!(empty.is_zero());
+}
+
+#[test]
+#[should_panic(expected = "cannot sum Decimal<13> values in the narrower Decimal<12>")]
+fn test_sum_widened_narrower() {
+    Context::<Decimal<13>>::default().sum_widened::<12>(&[]);
+}
+
+#[test]
+fn test_cbrt() {
+    const N: usize = 12;
+    fn cbrt(cx: &mut Context<Decimal<N>>, s: &str) -> String {
+        let mut d = cx.parse(s).unwrap();
+        cx.cbrt(&mut d);
+        d.to_string()
+    }
+
+    let mut cx = Context::<Decimal<N>>::default();
+    for (input, expected) in &[
+        ("2", "1.25992104989487316476721060727822835"),
+        ("3", "1.44224957030740838232163831078010959"),
+        ("0.5", "0.793700525984099737375852819636154130"),
+        ("123456789", "497.933859218174474402612501716043809"),
+        ("-2", "-1.25992104989487316476721060727822835"),
+        ("1E-100", "4.64158883361277889241007635091944658E-34"),
+        ("7E+1000", "4.12128529980855681937748911736641335E+333"),
+    ] {
+        cx.clear_status();
+        assert_eq!(cbrt(&mut cx, input), *expected, "cbrt({})", input);
+        assert!(cx.status().inexact());
+        assert!(cx.status().rounded());
+    }
+
+    cx.clear_status();
+    for (input, expected) in &[
+        ("8", "2"),
+        ("-27", "-3"),
+        ("1000", "10"),
+        ("1E+3", "1E+1"),
+        ("0.001", "0.1"),
+        ("1.000", "1.0"),
+        ("0", "0"),
+        ("-0", "-0"),
+        ("Infinity", "Infinity"),
+        ("-Infinity", "-Infinity"),
+        ("NaN", "NaN"),
+    ] {
+        assert_eq!(cbrt(&mut cx, input), *expected, "cbrt({})", input);
+    }
+    assert!(!cx.status().any());
+
+    let mut cx = ContextBuilder::<Decimal<N>>::new()
+        .precision(10)
+        .build()
+        .unwrap();
+    assert_eq!(cbrt(&mut cx, "2"), "1.259921050");
+    assert_eq!(cbrt(&mut cx, "0.5"), "0.7937005260");
+}
+
+#[test]
+fn test_to_sort_key_f64() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let mut ds: Vec<Decimal<12>> = [
+        "-Infinity",
+        "-1E+400",
+        "-1.7976931348623157E+308",
+        "-12345.678",
+        "-1",
+        "-0.1000000000000000000000000000000001",
+        "-0.1",
+        "-1E-400",
+        "-0",
+        "0",
+        "0.00",
+        "1E-400",
+        "0.1",
+        "0.1000000000000000000000000000000001",
+        "1",
+        "12345.678",
+        "1.7976931348623157E+308",
+        "1E+400",
+        "Infinity",
+    ]
+    .iter()
+    .map(|s| cx.parse(*s).unwrap())
+    .collect();
+    ds.sort_by(|a, b| cx.total_cmp(a, b));
+
+    let keys: Vec<f64> = ds.iter().map(|d| d.to_sort_key_f64()).collect();
+    for pair in keys.windows(2) {
+        assert!(pair[0] <= pair[1], "{} > {}", pair[0], pair[1]);
+    }
+    assert_eq!(keys[0], f64::NEG_INFINITY);
+    assert_eq!(keys[1], f64::NEG_INFINITY);
+    assert_eq!(keys[keys.len() - 1], f64::INFINITY);
+    let d = cx.parse("-12345.678").unwrap();
+    assert_eq!(d.to_sort_key_f64(), -12345.678);
+    assert!(Decimal::<12>::nan().to_sort_key_f64().is_nan());
+    let d = cx.parse("sNaN").unwrap();
+    assert!(d.to_sort_key_f64().is_nan());
+}
+
+#[test]
+fn test_decimal_max_min() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let one = Decimal::<12>::from(1);
+    let two = Decimal::<12>::from(2);
+    let neg = Decimal::<12>::from(-3);
+    let nan = Decimal::<12>::nan();
+    let snan = cx.parse("sNaN").unwrap();
+
+    assert_eq!(one.max(two).to_string(), "2");
+    assert_eq!(two.max(one).to_string(), "2");
+    assert_eq!(one.min(two).to_string(), "1");
+    assert_eq!(neg.min(one).to_string(), "-3");
+    assert_eq!(neg.max(Decimal::<12>::infinity()).to_string(), "Infinity");
+
+    // Quiet NaNs are ignored in favor of the other operand.
+    assert_eq!(nan.max(one).to_string(), "1");
+    assert_eq!(one.min(nan).to_string(), "1");
+    assert!(nan.max(nan).is_nan());
+
+    // Signaling NaNs propagate.
+    assert!(snan.max(one).is_nan());
+    assert!(one.min(snan).is_nan());
+
+    // Numerically equal operands are ordered by exponent.
+    let x = cx.parse("1.50").unwrap();
+    let y = cx.parse("1.5").unwrap();
+    assert_eq!(x.max(y).to_string(), "1.5");
+    assert_eq!(x.min(y).to_string(), "1.50");
+}
+
+#[test]
+fn test_status_policy() {
+    fn div_by_zero(cx: &mut Context<Decimal<12>>) -> Decimal<12> {
+        let mut d = Decimal::<12>::from(1);
+        cx.div(&mut d, &Decimal::<12>::zero());
+        d
+    }
+    let mut division_by_zero = Status::default();
+    division_by_zero.set_division_by_zero();
+    let mut inexact = Status::default();
+    inexact.set_inexact();
+
+    let mut cx = Context::<Decimal<12>>::default();
+    assert_eq!(cx.status_policy(), StatusPolicy::Ignore);
+    let d = cx.checked_op(div_by_zero).unwrap();
+    assert!(d.is_infinite());
+    assert!(cx.status().division_by_zero());
+
+    let mut cx = Context::<Decimal<12>>::default();
+    cx.set_status_policy(StatusPolicy::ReturnErr(division_by_zero));
+    let err = cx.checked_op(div_by_zero).unwrap_err();
+    assert!(err.status().division_by_zero());
+    assert!(cx.status().division_by_zero());
+    let d = cx
+        .checked_op(|cx| {
+            let mut d = Decimal::<12>::from(6);
+            cx.div(&mut d, &Decimal::<12>::from(3));
+            d
+        })
+        .unwrap();
+    assert_eq!(d.to_string(), "2");
+
+    // Conditions outside of the policy's set are ignored.
+    let mut cx = Context::<Decimal<12>>::default();
+    cx.set_status_policy(StatusPolicy::ReturnErr(inexact));
+    assert!(cx.checked_op(div_by_zero).unwrap().is_infinite());
+
+    let mut cx = Context::<Decimal<12>>::default();
+    cx.set_status_policy(StatusPolicy::Panic(inexact));
+    assert!(cx.checked_op(div_by_zero).unwrap().is_infinite());
+}
+
+#[test]
+#[should_panic(expected = "decimal operation raised exceptional conditions")]
+fn test_status_policy_panic() {
+    let mut division_by_zero = Status::default();
+    division_by_zero.set_division_by_zero();
+    let mut cx = Context::<Decimal<12>>::default();
+    cx.set_status_policy(StatusPolicy::Panic(division_by_zero));
+    let _ = cx.checked_op(|cx| {
+        let mut d = Decimal::<12>::from(1);
+        cx.div(&mut d, &Decimal::<12>::zero());
+    });
+}
+
+#[test]
+fn test_decimal_signum() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (input, expected) in &[
+        ("12.5", "1"),
+        ("1E-50", "1"),
+        ("-0.003", "-1"),
+        ("-1E+50", "-1"),
+        ("Infinity", "1"),
+        ("-Infinity", "-1"),
+        ("0", "0"),
+        ("0.000", "0"),
+        ("-0", "-0"),
+        ("-0E+3", "-0"),
+        ("NaN", "NaN"),
+        ("-NaN", "NaN"),
+        ("sNaN", "NaN"),
+    ] {
+        let d = cx.parse(*input).unwrap();
+        assert_eq!(d.signum().to_string(), *expected, "signum({})", input);
+    }
+    assert!(!cx.status().any());
+}
+
+#[test]
+fn test_parse_ratio() {
+    let mut cx = Context::<Decimal<12>>::default();
+
+    let d = cx.parse_ratio("3/4").unwrap();
+    assert_eq!(d.to_string(), "0.75");
+    assert!(!cx.status().inexact());
+
+    let d = cx.parse_ratio("1/3").unwrap();
+    assert_eq!(d.to_string(), "0.333333333333333333333333333333333333");
+    assert!(cx.status().inexact());
+    assert!(cx.status().rounded());
+
+    cx.clear_status();
+    let d = cx.parse_ratio("2").unwrap();
+    assert_eq!(d.to_string(), "2");
+    assert!(!cx.status().any());
+
+    let d = cx.parse_ratio("-1.5E+2/0.5").unwrap();
+    assert_eq!(d.to_string(), "-3E+2");
+
+    let d = cx.parse_ratio("1/0").unwrap();
+    assert!(d.is_infinite());
+    assert!(cx.status().division_by_zero());
+
+    for (s, position) in &[
+        ("", None),
+        ("/", None),
+        ("3/", None),
+        ("/4", None),
+        ("1/2/3", Some(3)),
+        ("a/4", Some(0)),
+        ("3/b", Some(2)),
+        ("3 / 4", Some(1)),
+    ] {
+        cx.clear_status();
+        let e = cx.parse_ratio(s).unwrap_err();
+        assert_eq!(e.position(), *position, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_clamp_value() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let min = cx.parse("-1.5").unwrap();
+    let max = cx.parse("10").unwrap();
+    for (input, expected) in &[
+        ("0", "0"),
+        ("-1.5", "-1.5"),
+        ("-1.50", "-1.50"),
+        ("-2", "-1.5"),
+        ("-Infinity", "-1.5"),
+        ("10", "10"),
+        ("1E+1", "10"),
+        ("10.5", "10"),
+        ("Infinity", "10"),
+        ("NaN", "NaN"),
+    ] {
+        let mut d = cx.parse(*input).unwrap();
+        cx.clamp_value(&mut d, &min, &max);
+        assert_eq!(d.to_string(), *expected, "clamp_value({})", input);
+    }
+    assert!(!cx.status().any());
+
+    let mut d = cx.parse("sNaN").unwrap();
+    cx.clamp_value(&mut d, &min, &max);
+    assert!(d.is_quiet_nan());
+    assert!(cx.status().invalid_operation());
+
+    let nan = Decimal::<12>::nan();
+    for (lo, hi) in &[(&nan, &max), (&min, &nan), (&max, &min)] {
+        cx.clear_status();
+        let mut d = Decimal::<12>::from(1);
+        cx.clamp_value(&mut d, lo, hi);
+        assert!(d.is_nan());
+        assert!(cx.status().invalid_operation());
+    }
+}
+
+#[test]
+fn test_tagged_bytes() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let d = cx.parse("-12.345").unwrap();
+    assert_eq!(
+        d.to_tagged_bytes(),
+        vec![1, 5, 0, 0, 0, 0x80, 0xfd, 0xff, 0xff, 0xff, 0x12, 0x34, 0x50]
+    );
+
+    for s in &[
+        "0",
+        "-0",
+        "0E-7",
+        "1",
+        "-12.345",
+        "123456789012345678901234567890123456",
+        "1.5E+999999999",
+        "-7E-1000000033",
+        "Infinity",
+        "-Infinity",
+        "NaN",
+        "-NaN123",
+        "sNaN456",
+    ] {
+        let d = cx.parse(*s).unwrap();
+        let bytes = d.to_tagged_bytes();
+
+        let same = Decimal::<12>::from_tagged_bytes(&bytes).unwrap();
+        assert_eq!(same.to_string(), d.to_string(), "{}", s);
+        assert_eq!(same.to_tagged_bytes(), bytes, "{}", s);
+
+        let wide = Decimal::<20>::from_tagged_bytes(&bytes).unwrap();
+        assert_eq!(wide.to_string(), d.to_string(), "{}", s);
+        assert_eq!(wide.to_tagged_bytes(), bytes, "{}", s);
+        let narrow = Decimal::<12>::from_tagged_bytes(&wide.to_tagged_bytes()).unwrap();
+        assert_eq!(narrow.to_string(), d.to_string(), "{}", s);
+    }
+
+    // A value that needs more capacity than the target provides.
+    let mut wide_cx = Context::<Decimal<20>>::default();
+    let wide = wide_cx
+        .parse("1234567890123456789012345678901234567890")
+        .unwrap();
+    assert_eq!(
+        Decimal::<12>::from_tagged_bytes(&wide.to_tagged_bytes()),
+        Err(InvalidTaggedBytesError)
+    );
+
+    let bytes = cx.parse("-12.345").unwrap().to_tagged_bytes();
+    for len in 0..bytes.len() {
+        assert_eq!(
+            Decimal::<12>::from_tagged_bytes(&bytes[..len]),
+            Err(InvalidTaggedBytesError),
+            "truncated to {} bytes",
+            len
+        );
+    }
+    let mut extended = bytes.clone();
+    extended.push(0);
+    for invalid in &[
+        extended,
+        // Unknown version.
+        vec![
+            2, 5, 0, 0, 0, 0x80, 0xfd, 0xff, 0xff, 0xff, 0x12, 0x34, 0x50,
+        ],
+        // Non-decimal nibble.
+        vec![
+            1, 5, 0, 0, 0, 0x80, 0xfd, 0xff, 0xff, 0xff, 0x12, 0x3a, 0x50,
+        ],
+        // Nonzero padding nibble.
+        vec![
+            1, 5, 0, 0, 0, 0x80, 0xfd, 0xff, 0xff, 0xff, 0x12, 0x34, 0x51,
+        ],
+        // Leading zero.
+        vec![
+            1, 5, 0, 0, 0, 0x80, 0xfd, 0xff, 0xff, 0xff, 0x02, 0x34, 0x50,
+        ],
+        // No digits.
+        vec![1, 0, 0, 0, 0, 0x00, 0x00, 0x00, 0x00, 0x00],
+        // Unknown flags.
+        vec![1, 1, 0, 0, 0, 0x01, 0x00, 0x00, 0x00, 0x00, 0x10],
+        // Conflicting flags.
+        vec![1, 1, 0, 0, 0, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00],
+        // Infinity with a coefficient.
+        vec![1, 1, 0, 0, 0, 0x40, 0x00, 0x00, 0x00, 0x00, 0x10],
+        // Exponent out of range.
+        vec![1, 2, 0, 0, 0, 0x00, 0xff, 0xc9, 0x9a, 0x3b, 0x10],
+    ] {
+        assert_eq!(
+            Decimal::<12>::from_tagged_bytes(invalid),
+            Err(InvalidTaggedBytesError),
+            "{:?}",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_fixed_width_bytes() {
+    assert_eq!(
+        "1".parse::<Decimal32>().unwrap().to_be_bytes(),
+        [0x22, 0x50, 0x00, 0x01]
+    );
+    assert_eq!(
+        "1".parse::<Decimal64>().unwrap().to_be_bytes(),
+        [0x22, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]
+    );
+    assert_eq!(
+        "1".parse::<Decimal128>().unwrap().to_be_bytes(),
+        [0x22, 0x08, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01]
+    );
+
+    macro_rules! test_round_trip {
+        ($t:ty) => {
+            for s in &[
+                "0",
+                "-0",
+                "1",
+                "-12.345",
+                "9.99E+90",
+                "1E-95",
+                "Infinity",
+                "-Infinity",
+                "NaN",
+                "-NaN123",
+                "sNaN45",
+                "-sNaN",
+            ] {
+                let d: $t = s.parse().unwrap();
+                let be = d.to_be_bytes();
+                let le = d.to_le_bytes();
+                let ne = d.to_ne_bytes();
+                let mut reversed = be;
+                reversed.reverse();
+                assert_eq!(le, reversed, "{}", s);
+                if cfg!(target_endian = "little") {
+                    assert_eq!(ne, le, "{}", s);
+                } else {
+                    assert_eq!(ne, be, "{}", s);
+                }
+                assert_eq!(<$t>::from_be_bytes(be).to_be_bytes(), be, "{}", s);
+                assert_eq!(<$t>::from_le_bytes(le).to_be_bytes(), be, "{}", s);
+                assert_eq!(<$t>::from_ne_bytes(ne).to_be_bytes(), be, "{}", s);
+                assert_eq!(<$t>::from_be_bytes(be).to_string(), d.to_string(), "{}", s);
+            }
+        };
+    }
+    test_round_trip!(Decimal32);
+    test_round_trip!(Decimal64);
+    test_round_trip!(Decimal128);
+}
+
+#[test]
+fn test_max0() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (input, expected) in &[
+        ("-5", "0"),
+        ("5", "5"),
+        ("0.00", "0.00"),
+        ("-0", "0"),
+        ("-1E-100", "0"),
+        ("-Infinity", "0"),
+        ("Infinity", "Infinity"),
+        ("NaN", "NaN"),
+        ("-NaN", "-NaN"),
+    ] {
+        let mut d = cx.parse(*input).unwrap();
+        cx.max0(&mut d);
+        assert_eq!(d.to_string(), *expected, "max0({})", input);
+    }
+    assert!(!cx.status().any());
+
+    let mut d = cx.parse("sNaN").unwrap();
+    cx.max0(&mut d);
+    assert!(d.is_quiet_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_fixed_width_from_str() {
+    assert_eq!("3.14".parse::<Decimal32>().unwrap().to_string(), "3.14");
+    assert_eq!("3.14".parse::<Decimal64>().unwrap().to_string(), "3.14");
+    assert_eq!("3.14".parse::<Decimal128>().unwrap().to_string(), "3.14");
+
+    // Inexact parses round to the width of the type rather than failing.
+    let s = "1.234567890123456789012345678901234567890";
+    assert_eq!(s.parse::<Decimal32>().unwrap().to_string(), "1.234568");
+    assert_eq!(
+        s.parse::<Decimal64>().unwrap().to_string(),
+        "1.234567890123457"
+    );
+    assert_eq!(
+        s.parse::<Decimal128>().unwrap().to_string(),
+        "1.234567890123456789012345678901235"
+    );
+
+    for s in &["", "1.2.3", "abc", "1e", "--1"] {
+        let e = s.parse::<Decimal32>().unwrap_err();
+        assert_eq!(s.parse::<Decimal64>(), Err(e), "{:?}", s);
+        assert_eq!(s.parse::<Decimal128>(), Err(e), "{:?}", s);
+        assert_eq!(s.parse::<Decimal<12>>(), Err(e), "{:?}", s);
+    }
+}
+
+#[test]
+fn test_running_stats() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let mut stats = RunningStats::<12>::new();
+    assert_eq!(stats.count(), 0);
+    assert!(stats.sum().is_zero());
+    assert!(stats.mean().is_none());
+    assert!(stats.variance(&mut cx).is_none());
+    assert!(stats.stddev(&mut cx).is_none());
+    assert!(stats.min().is_none());
+    assert!(stats.max().is_none());
+
+    let xs: Vec<Decimal<12>> = ["3.5", "-1.25", "10", "0.001", "7.75", "2", "-4.5", "6.125"]
+        .iter()
+        .map(|s| cx.parse(*s).unwrap())
+        .collect();
+    for x in &xs {
+        stats.push(&mut cx, x);
+    }
+
+    let count = Decimal::<12>::from(xs.len() as u64);
+    let sum = cx.sum(xs.iter());
+    let mut mean = sum;
+    cx.div(&mut mean, &count);
+    let mut sq_diffs = Decimal::<12>::zero();
+    for x in &xs {
+        let mut diff = *x;
+        cx.sub(&mut diff, &mean);
+        let d = diff;
+        cx.mul(&mut diff, &d);
+        cx.add(&mut sq_diffs, &diff);
+    }
+    let mut variance = sq_diffs;
+    cx.div(&mut variance, &count);
+    let mut stddev = variance;
+    cx.sqrt(&mut stddev);
+
+    // The incremental computations may differ from the batch computations in
+    // the last digit.
+    let mut close = |a: Decimal<12>, b: Decimal<12>| {
+        let mut diff = a;
+        cx.sub(&mut diff, &b);
+        cx.abs(&mut diff);
+        let tolerance = cx.parse("1E-30").unwrap();
+        cx.partial_cmp(&diff, &tolerance) != Some(std::cmp::Ordering::Greater)
+    };
+
+    assert_eq!(stats.count(), 8);
+    assert_eq!(stats.sum(), sum);
+    assert_eq!(stats.sum().to_string(), "23.626");
+    assert!(close(stats.mean().unwrap(), mean));
+    let v = stats.variance(&mut Context::default()).unwrap();
+    assert!(close(v, variance));
+    let s = stats.stddev(&mut Context::default()).unwrap();
+    assert!(close(s, stddev));
+    assert_eq!(stats.min().unwrap().to_string(), "-4.5");
+    assert_eq!(stats.max().unwrap().to_string(), "10");
+
+    let mut single = RunningStats::<12>::default();
+    single.push(&mut cx, &Decimal::from(-3));
+    assert_eq!(single.mean().unwrap().to_string(), "-3");
+    assert!(single.variance(&mut cx).unwrap().is_zero());
+    assert_eq!(single.min().unwrap().to_string(), "-3");
+    assert_eq!(single.max().unwrap().to_string(), "-3");
+}
+
+#[test]
+fn test_fixed_width_display() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for s in &[
+        "0",
+        "-0",
+        "0E-3",
+        "-12.345",
+        "1.23E+5",
+        "1.23E-7",
+        "123E+3",
+        "Infinity",
+        "-Infinity",
+        "NaN",
+        "-NaN12",
+        "sNaN",
+        "-sNaN3",
+    ] {
+        let expected = cx.parse(*s).unwrap();
+        let d32: Decimal32 = s.parse().unwrap();
+        let d64: Decimal64 = s.parse().unwrap();
+        let d128: Decimal128 = s.parse().unwrap();
+        for (display, debug, alternate) in &[
+            (d32.to_string(), format!("{:?}", d32), format!("{:#}", d32)),
+            (d64.to_string(), format!("{:?}", d64), format!("{:#}", d64)),
+            (
+                d128.to_string(),
+                format!("{:?}", d128),
+                format!("{:#}", d128),
+            ),
+        ] {
+            assert_eq!(*display, expected.to_string(), "{}", s);
+            assert_eq!(*debug, expected.to_string(), "{}", s);
+            assert_eq!(*alternate, format!("{:#}", expected), "{}", s);
+        }
+    }
+}
+
+#[test]
+fn test_fixed_width_operators() {
+    macro_rules! test_operators {
+        ($t:ty) => {
+            let a: $t = "7.5".parse().unwrap();
+            let b: $t = "2".parse().unwrap();
+            assert_eq!((a + b).to_string(), "9.5");
+            assert_eq!((a - b).to_string(), "5.5");
+            assert_eq!((a * b).to_string(), "15.0");
+            assert_eq!((a / b).to_string(), "3.75");
+            assert_eq!((a % b).to_string(), "1.5");
+            assert_eq!((-a).to_string(), "-7.5");
+
+            let mut c = a;
+            c += b;
+            c -= b;
+            c *= b;
+            c /= b;
+            assert_eq!(c, a);
+
+            // The context methods agree with the operators, and report
+            // exceptional conditions through the context's status.
+            let mut cx = Context::<$t>::default();
+            assert_eq!(cx.add(a, b), a + b);
+            assert_eq!(cx.sub(a, b), a - b);
+            assert_eq!(cx.mul(a, b), a * b);
+            assert_eq!(cx.div(a, b), a / b);
+            assert!(!cx.status().any());
+            let zero: $t = "0".parse().unwrap();
+            assert!(cx.div(a, zero).is_infinite());
+            assert!(cx.status().division_by_zero());
+            cx.clear_status();
+            let six: $t = "6".parse().unwrap();
+            assert_eq!(cx.div(b, six), b / six);
+            assert!(cx.status().inexact());
+        };
+    }
+    test_operators!(Decimal64);
+    test_operators!(Decimal128);
+}
+
+#[test]
+fn test_mean() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let xs: Vec<Decimal<12>> = ["1.5", "2.5", "-1", "5"]
+        .iter()
+        .map(|s| cx.parse(*s).unwrap())
+        .collect();
+    assert_eq!(cx.mean(xs.iter()).to_string(), "2.0");
+    assert!(!cx.status().any());
+
+    let xs: Vec<Decimal<12>> = (1..=2).map(Decimal::from).collect();
+    assert_eq!(cx.mean(xs.iter()).to_string(), "1.5");
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .build()
+        .unwrap();
+    let xs = [Decimal::<12>::from(1), Decimal::from(1), Decimal::from(0)];
+    assert_eq!(cx.mean(xs.iter()).to_string(), "0.66667");
+    assert!(cx.status().inexact());
+
+    let mut cx = Context::<Decimal<12>>::default();
+    assert!(cx.mean([].iter()).is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_to_fixed_width_exact() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let mut parse = |s: &str| cx.parse(s).unwrap();
+
+    let d = parse("-1.5");
+    assert_eq!(d.to_decimal32_exact().unwrap().to_string(), "-1.5");
+    assert_eq!(d.to_decimal64_exact().unwrap().to_string(), "-1.5");
+    assert_eq!(d.to_decimal128_exact().unwrap().to_string(), "-1.5");
+
+    // Too many digits.
+    let d = parse("12345678901234567");
+    assert_eq!(d.to_decimal32_exact().unwrap_err(), InexactError);
+    assert_eq!(d.to_decimal64_exact(), Err(InexactError));
+    assert_eq!(
+        d.to_decimal128_exact().unwrap().to_string(),
+        "12345678901234567"
+    );
+
+    // Trailing zeros may be dropped without changing the value.
+    let d = parse("1.00000000000000000000");
+    assert_eq!(
+        d.to_decimal64_exact().unwrap().to_string(),
+        "1.000000000000000"
+    );
+
+    // Overflow and underflow.
+    for s in &["1E+400", "1E-420"] {
+        let d = parse(s);
+        assert_eq!(d.to_decimal64_exact(), Err(InexactError), "{}", s);
+        assert_eq!(d.to_decimal128_exact().unwrap().to_string(), *s);
+    }
+
+    for s in &["Infinity", "-Infinity", "NaN", "-0"] {
+        let d = parse(s);
+        assert_eq!(d.to_decimal32_exact().unwrap().to_string(), *s);
+        assert_eq!(d.to_decimal64_exact().unwrap().to_string(), *s);
+        assert_eq!(d.to_decimal128_exact().unwrap().to_string(), *s);
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    use ParseDecimalErrorKind::*;
+
+    for (s, kind, position) in &[
+        ("", Empty, None),
+        ("-", MissingDigit, Some(1)),
+        (".", MissingDigit, Some(1)),
+        ("e5", UnexpectedCharacter, Some(0)),
+        ("1e", MissingDigit, Some(2)),
+        ("1e+", MissingDigit, Some(3)),
+        ("1.2.3", MultipleDecimalPoints, Some(3)),
+        ("--1", UnexpectedCharacter, Some(1)),
+        (" 1", UnexpectedCharacter, Some(0)),
+        ("1 ", UnexpectedCharacter, Some(1)),
+        ("1,000", UnexpectedCharacter, Some(1)),
+        ("1e5.0", UnexpectedCharacter, Some(3)),
+        ("1\0", UnexpectedCharacter, Some(1)),
+        ("abc", UnexpectedCharacter, Some(0)),
+        ("-infx", UnexpectedCharacter, Some(4)),
+        ("Infinity1", UnexpectedCharacter, Some(8)),
+        ("nan12x", UnexpectedCharacter, Some(5)),
+        ("nan1234567890123456789012345678901234567890", Invalid, None),
+    ] {
+        let e = s.parse::<Decimal128>().unwrap_err();
+        assert_eq!(e.kind(), *kind, "{:?}", s);
+        assert_eq!(e.position(), *position, "{:?}", s);
+    }
+
+    let e = "1.2.3".parse::<Decimal64>().unwrap_err();
+    assert_eq!(e.to_string(), "invalid decimal syntax");
+    let e = "".parse::<Decimal64>().unwrap_err();
+    assert_eq!(e.to_string(), "invalid decimal syntax");
+}
+
+#[test]
+fn test_decimal_ulp() {
+    for (input, expected) in &[
+        ("1.230", "0.001"),
+        ("-5E+3", "1E+3"),
+        ("0", "1"),
+        ("-0.00", "0.01"),
+        ("123456789", "1"),
+        ("1E-999999999", "1E-999999999"),
+        ("Infinity", "NaN"),
+        ("-NaN", "NaN"),
+        ("sNaN", "NaN"),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        assert_eq!(d.ulp().to_string(), *expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_ulp_distance() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(3)
+        .max_exponent(5)
+        .min_exponent(-2)
+        .build()
+        .unwrap();
+
+    // Compare against counting `next_toward` steps, across powers of ten,
+    // subnormals, and zero.
+    let values = [
+        "1", "1.01", "0.999", "9.99", "10.0", "10", "123", "-1", "-0", "0", "0.01", "0.001",
+        "0.0001", "-0.0001", "-0.99", "3.14E+5",
+    ];
+    for a in &values {
+        for b in &values {
+            let a: Decimal<12> = a.parse().unwrap();
+            let b: Decimal<12> = b.parse().unwrap();
+            let mut x = a;
+            let mut steps = 0;
+            while cx.partial_cmp(&x, &b) != Some(Ordering::Equal) {
+                cx.next_toward(&mut x, &b);
+                steps += 1;
+            }
+            assert_eq!(
+                cx.ulp_distance(&a, &b).unwrap().to_string(),
+                steps.to_string(),
+                "{} {}",
+                a,
+                b
+            );
+        }
+    }
+
+    let one: Decimal<12> = "1".parse().unwrap();
+    for s in &["NaN", "-Infinity", "1.234", "1E+6", "1E-5"] {
+        let d: Decimal<12> = s.parse().unwrap();
+        assert_eq!(cx.ulp_distance(&one, &d), None, "{}", s);
+        assert_eq!(cx.ulp_distance(&d, &one), None, "{}", s);
+    }
+
+    let mut cx = Context::<Decimal<12>>::default();
+    let a: Decimal<12> = "0.999999999999999999999999999999999998".parse().unwrap();
+    let b: Decimal<12> = "1.00000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(cx.ulp_distance(&a, &b).unwrap().to_string(), "3");
+    for s in &["1.5", "-1E+100", "0"] {
+        let a: Decimal<12> = s.parse().unwrap();
+        assert_eq!(cx.ulp_distance(&a, &a).unwrap().to_string(), "0", "{}", s);
+    }
+    assert!(!cx.status().any());
+}
+
+#[test]
+fn test_decimal_classify() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for s in &[
+        "sNaN",
+        "-NaN",
+        "NaN",
+        "Infinity",
+        "-Infinity",
+        "0",
+        "-0.00",
+        "1.5",
+        "-1E+999999999",
+        "1E-999999999",
+        "-0.1E-999999999",
+        "1E-1000000034",
+    ] {
+        let d: Decimal<12> = s.parse().unwrap();
+        assert_eq!(d.classify(), cx.class(&d), "{}", s);
+    }
+}
+
+#[test]
+fn test_decimal_neg_ref() {
+    for s in &["1.50", "-2E+3", "0", "-0", "Infinity", "-NaN", "sNaN"] {
+        let d: Decimal<12> = s.parse().unwrap();
+        assert_eq!((-&d).to_string(), (-d).to_string(), "{}", s);
+    }
+    let d: Decimal<12> = "1.50".parse().unwrap();
+    assert_eq!((-&d).to_string(), "-1.50");
+    assert_eq!(d.to_string(), "1.50");
+}
+
+#[test]
+fn test_decimal_assign_ref() {
+    let xs: Vec<Decimal<12>> = ["1.5", "2.25", "-0.75"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+    let mut sum = Decimal::<12>::zero();
+    let mut product = Decimal::<12>::from(1);
+    for x in &xs {
+        sum += x;
+        product *= x;
+    }
+    assert_eq!(sum.to_string(), "3.00");
+    assert_eq!(product.to_string(), "-2.53125");
+
+    sum -= &xs[0];
+    assert_eq!(sum.to_string(), "1.50");
+    sum /= &xs[2];
+    assert_eq!(sum.to_string(), "-2");
+
+    let mut d = Decimal::<12>::from(1);
+    d /= &Decimal::zero();
+    assert!(d.is_infinite());
+}
+
+#[test]
+fn test_coefficient_digits_into() {
+    let mut buf = [0xff; 36];
+    for s in &["0", "-1.23", "9876543210E+5", "NaN123", "Infinity"] {
+        let d: Decimal<12> = s.parse().unwrap();
+        let n = d.coefficient_digits_into(&mut buf).unwrap();
+        assert_eq!(&buf[..n], &d.coefficient_digits()[..], "{}", s);
+    }
+
+    let d: Decimal<12> = "12345".parse().unwrap();
+    let mut buf = [0xff; 6];
+    assert_eq!(d.coefficient_digits_into(&mut buf), Ok(5));
+    assert_eq!(buf, [1, 2, 3, 4, 5, 0xff]);
+    assert_eq!(
+        d.coefficient_digits_into(&mut buf[..4]),
+        Err(BufferTooSmallError)
+    );
+}
+
+#[test]
+fn test_decimal_write_to() {
+    let mut buf = String::new();
+    for s in &[
+        "0",
+        "-1.50",
+        "1E+999999999",
+        "-1.23456789012345678901234567890123456E-999999999",
+        "123456789012345678901234567890123456",
+        "-Infinity",
+        "NaN123456789012345678901234567890123456",
+        "-sNaN",
+    ] {
+        let d: Decimal<12> = s.parse().unwrap();
+        buf.clear();
+        d.write_to(&mut buf).unwrap();
+        assert_eq!(buf, d.to_string(), "{}", s);
+    }
+
+    let d: Decimal<12> = "1.5E+7".parse().unwrap();
+    buf.clear();
+    d.write_to(&mut buf).unwrap();
+    assert_eq!(buf, "1.5E+7");
+    assert_eq!(format!("{:#}", d), "15E+6");
+}
+
+#[test]
+fn test_decimal_consts() {
+    static ZERO: Decimal<12> = Decimal::ZERO;
+    const INFINITY: Decimal<12> = Decimal::infinity();
+
+    assert!(ZERO.is_zero());
+    assert!(!ZERO.is_negative());
+    assert_eq!(ZERO.to_string(), "0");
+    assert_eq!(ZERO.to_string(), Decimal::<12>::default().to_string());
+    assert!(INFINITY.is_infinite());
+    assert_eq!(Decimal::<12>::INFINITY.to_string(), "Infinity");
+    assert!(Decimal::<12>::NAN.is_quiet_nan());
+    assert_eq!(Decimal::<13>::NAN.to_string(), "NaN");
+}
+
+#[test]
+fn test_decimal_limits() {
+    assert_eq!(Decimal::<12>::MAX_PRECISION, 36);
+    assert_eq!(Decimal::<14>::MAX_PRECISION, 42);
+    assert_eq!(Decimal::<12>::MIN_N, 12);
+    assert_eq!(Decimal::<12>::MAX_N, 999_999_999);
+
+    let mut cx = Context::<Decimal<12>>::default();
+    assert_eq!(cx.precision(), Decimal::<12>::MAX_PRECISION);
+    assert!(cx.set_precision(Decimal::<12>::MAX_PRECISION).is_ok());
+    assert!(cx.set_precision(Decimal::<12>::MAX_PRECISION + 1).is_err());
+
+    type Cx = Context<Decimal<12>>;
+    assert_eq!(cx.max_exponent(), Cx::MAX_EMAX);
+    assert_eq!(cx.min_exponent(), Cx::MIN_EMIN);
+    assert!(cx.set_max_exponent(Cx::MAX_EMAX).is_ok());
+    assert!(cx.set_max_exponent(Cx::MAX_EMAX + 1).is_err());
+    assert!(cx.set_min_exponent(Cx::MIN_EMIN).is_ok());
+    assert!(cx.set_min_exponent(Cx::MIN_EMIN - 1).is_err());
+}
+
+#[test]
+fn test_decimal_abs() {
+    for (input, expected) in &[
+        ("1.50", "1.50"),
+        ("-1.50", "1.50"),
+        ("-0", "0"),
+        (
+            "-1.23456789012345678901234567890123456E-999999999",
+            "1.23456789012345678901234567890123456E-999999999",
+        ),
+        ("-Infinity", "Infinity"),
+        ("-NaN12", "NaN12"),
+        ("-sNaN", "sNaN"),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        assert_eq!(d.abs().to_string(), *expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_decimal_saturating_to_int() {
+    for (input, i32_, i64_, u32_, u64_) in &[
+        ("0", 0, 0, 0, 0),
+        ("-0", 0, 0, 0, 0),
+        ("1.9", 1, 1, 1, 1),
+        ("-1.9", -1, -1, 0, 0),
+        ("-0.5", 0, 0, 0, 0),
+        ("1E+2", 100, 100, 100, 100),
+        ("2147483647.9", i32::MAX, 2147483647, 2147483647, 2147483647),
+        ("2147483648", i32::MAX, 2147483648, 2147483648, 2147483648),
+        ("-2147483649", i32::MIN, -2147483649, 0, 0),
+        ("4294967296", i32::MAX, 4294967296, u32::MAX, 4294967296),
+        ("1E+50", i32::MAX, i64::MAX, u32::MAX, u64::MAX),
+        ("-1E+50", i32::MIN, i64::MIN, 0, 0),
+        ("Infinity", i32::MAX, i64::MAX, u32::MAX, u64::MAX),
+        ("-Infinity", i32::MIN, i64::MIN, 0, 0),
+        ("NaN", 0, 0, 0, 0),
+        ("-sNaN", 0, 0, 0, 0),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        assert_eq!(d.saturating_to_i32(), *i32_, "{}", input);
+        assert_eq!(d.saturating_to_i64(), *i64_, "{}", input);
+        assert_eq!(d.saturating_to_u32(), *u32_, "{}", input);
+        assert_eq!(d.saturating_to_u64(), *u64_, "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_localized() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (s, decimal, grouping, expected) in &[
+        ("1.234,56", ',', Some('.'), "1234.56"),
+        ("-1,234.56", '.', Some(','), "-1234.56"),
+        ("1 234 567,5", ',', Some(' '), "1234567.5"),
+        ("1’000’000", '.', Some('’'), "1000000"),
+        ("0,5", ',', None, "0.5"),
+        ("1,5E+3", ',', None, "1.5E+3"),
+        ("1.5", '.', None, "1.5"),
+    ] {
+        let d = cx.parse_localized(s, *decimal, *grouping).unwrap();
+        assert_eq!(d.to_string(), *expected, "{:?}", s);
+    }
+
+    for (s, decimal, grouping, kind, position) in &[
+        (
+            "1.5",
+            ',',
+            None,
+            ParseDecimalErrorKind::UnexpectedCharacter,
+            Some(1),
+        ),
+        (
+            "1.234.5",
+            '.',
+            Some(','),
+            ParseDecimalErrorKind::MultipleDecimalPoints,
+            Some(5),
+        ),
+        (
+            "1’000x",
+            '.',
+            Some('’'),
+            ParseDecimalErrorKind::UnexpectedCharacter,
+            Some(7),
+        ),
+        (
+            "1,2,3",
+            ',',
+            None,
+            ParseDecimalErrorKind::MultipleDecimalPoints,
+            Some(3),
+        ),
+        (
+            "1,5e",
+            ',',
+            None,
+            ParseDecimalErrorKind::MissingDigit,
+            Some(4),
+        ),
+    ] {
+        cx.clear_status();
+        let e = cx.parse_localized(s, *decimal, *grouping).unwrap_err();
+        assert_eq!(e.kind(), *kind, "{:?}", s);
+        assert_eq!(e.position(), *position, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_powi() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (x, n, expected) in &[
+        ("1.1", 10, "2.5937424601"),
+        ("1.05", 30, "4.32194237515066200915728819888647334"),
+        ("2", -2, "0.25"),
+        ("-2", 3, "-8"),
+        ("-2", -3, "-0.125"),
+        ("10", 40, "1.00000000000000000000000000000000000E+40"),
+        ("3", -1, "0.333333333333333333333333333333333333"),
+        ("7.5", 0, "1"),
+        ("0", 0, "1"),
+        ("-0", 3, "-0"),
+        ("0", 2, "0"),
+        ("-Infinity", 3, "-Infinity"),
+        ("Infinity", -1, "0E-1000000034"),
+        ("Infinity", 0, "1"),
+        ("NaN", 0, "NaN"),
+        ("10", i32::MIN, "0E-1000000034"),
+    ] {
+        cx.clear_status();
+        let mut d: Decimal<12> = x.parse().unwrap();
+        cx.powi(&mut d, *n);
+        assert_eq!(d.to_string(), *expected, "{}^{}", x, n);
+    }
+
+    // Exact results raise no conditions.
+    cx.clear_status();
+    let mut d: Decimal<12> = "1.1".parse().unwrap();
+    cx.powi(&mut d, 10);
+    assert!(!cx.status().any());
+
+    cx.set_precision(5).unwrap();
+    cx.clear_status();
+    let mut d: Decimal<12> = "1.1".parse().unwrap();
+    cx.powi(&mut d, 10);
+    assert_eq!(d.to_string(), "2.5937");
+    assert!(cx.status().inexact());
+    assert!(cx.status().rounded());
+
+    let mut d: Decimal<12> = "-0".parse().unwrap();
+    cx.clear_status();
+    cx.powi(&mut d, -1);
+    assert_eq!(d.to_string(), "-Infinity");
+    assert!(cx.status().division_by_zero());
+}
+
+#[test]
+fn test_poly_eval() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .max_exponent(999_999)
+        .min_exponent(-999_999)
+        .build()
+        .unwrap();
+    let coeffs: Vec<Decimal<12>> = ["1", "-3", "0", "2.5"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    for (x, expected) in &[
+        ("0", "1.0"),
+        ("1", "0.5"),
+        ("2", "15.0"),
+        ("-1.5", "-2.9375"),
+        ("NaN", "NaN"),
+    ] {
+        let x: Decimal<12> = x.parse().unwrap();
+        let d = cx.poly_eval(&coeffs, &x);
+        assert_eq!(d.to_string(), *expected, "{}", x);
+    }
+    assert!(!cx.status().any());
+
+    let x: Decimal<12> = "7".parse().unwrap();
+    assert!(cx.poly_eval(&[], &x).is_zero());
+    assert_eq!(cx.poly_eval(&coeffs[..1], &x).to_string(), "1");
+
+    let mut default_cx = Context::<Decimal<12>>::default();
+    assert!(default_cx.poly_eval(&coeffs, &x).is_nan());
+    assert!(default_cx.status().invalid_context());
+
+    // Each step is rounded once.
+    cx.set_precision(3).unwrap();
+    let coeffs: Vec<Decimal<12>> = ["0.001", "1", "1"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let x: Decimal<12> = "0.999".parse().unwrap();
+    assert_eq!(cx.poly_eval(&coeffs, &x).to_string(), "2.00");
+    assert!(cx.status().inexact());
+}
+
+#[test]
+fn test_decimal_normalized() {
+    for (input, expected) in &[
+        ("1.200", "1.2"),
+        ("12.0E-1", "1.2"),
+        ("1200", "1.2E+3"),
+        ("0.000", "0"),
+        ("-0E+5", "-0"),
+        ("-7", "-7"),
+        ("Infinity", "Infinity"),
+        ("NaN", "NaN"),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        let n = d.normalized();
+        assert_eq!(n.to_string(), *expected, "{}", input);
+        if !d.is_nan() {
+            assert_eq!(n, d, "{}", input);
+        }
+    }
+
+    let a: Decimal<12> = "1.50".parse().unwrap();
+    let b: Decimal<12> = "15E-1".parse().unwrap();
+    assert_eq!(
+        a.normalized().to_raw_parts().0,
+        b.normalized().to_raw_parts().0
+    );
+    assert_eq!(a.normalized().exponent(), b.normalized().exponent());
+}
+
+#[test]
+fn test_decimal_cmp_primitive() {
+    let d: Decimal<12> = "100.5".parse().unwrap();
+    assert!(d > 100);
+    assert!(d < 101);
+    assert!(100 < d);
+    assert!(d != 100);
+    assert!(d > 100i64 && d > 100u32 && d > 100u64 && d > 100i128 && d > 100u128);
+
+    let d: Decimal<12> = "1.00E+2".parse().unwrap();
+    assert!(d == 100);
+    assert!(100u64 == d);
+    assert_eq!(d.partial_cmp(&100i128), Some(Ordering::Equal));
+
+    let d: Decimal<12> = "-0".parse().unwrap();
+    assert!(d == 0u32);
+    assert!(d > -1);
+
+    // 128-bit integers with more digits than a `Decimal<12>` can hold are
+    // compared exactly.
+    let max: Decimal<12> = "1.70141183460469231731687303715884106E+38".parse().unwrap();
+    assert!(max > i128::MAX);
+    assert!(i128::MAX < max);
+    assert!(max != i128::MAX);
+    assert!(Decimal::<14>::from(0) < u128::MAX);
+    let mut cx = Context::<Decimal<14>>::default();
+    assert!(cx.from_u128(u128::MAX) == u128::MAX);
+    assert!(cx.from_i128(i128::MIN) == i128::MIN);
+    assert!(cx.from_i128(i128::MIN) < i128::MIN + 1);
+
+    for s in &["NaN", "-sNaN"] {
+        let d: Decimal<12> = s.parse().unwrap();
+        assert!(d != 0);
+        assert!(!d.lt(&0) && !d.gt(&0) && !d.le(&0) && !d.ge(&0));
+        assert_eq!(d.partial_cmp(&0u128), None);
+        assert_eq!(0i64.partial_cmp(&d), None);
+    }
+
+    let inf: Decimal<12> = "-Infinity".parse().unwrap();
+    assert!(inf < i128::MIN);
+}
+
+#[test]
+fn test_div_rem() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (lhs, rhs, q, r) in &[
+        ("7", "2", "3", "1"),
+        ("-7", "2", "-3", "-1"),
+        ("7", "-2", "-3", "1"),
+        ("7.5", "2", "3", "1.5"),
+        ("1", "0.3", "3", "0.1"),
+        ("0", "5", "0", "0"),
+        ("2", "Infinity", "0", "2"),
+    ] {
+        let lhs: Decimal<12> = lhs.parse().unwrap();
+        let rhs: Decimal<12> = rhs.parse().unwrap();
+        let (quotient, remainder) = cx.div_rem(&lhs, &rhs);
+        assert_eq!(quotient.to_string(), *q, "{} / {}", lhs, rhs);
+        assert_eq!(remainder.to_string(), *r, "{} % {}", lhs, rhs);
+        if rhs.is_finite() {
+            let mut check = quotient;
+            cx.mul(&mut check, &rhs);
+            cx.add(&mut check, &remainder);
+            assert_eq!(check, lhs, "{} / {}", lhs, rhs);
+        }
+    }
+    assert!(!cx.status().any());
+
+    let lhs: Decimal<12> = "1E+50".parse().unwrap();
+    let (q, r) = cx.div_rem(&lhs, &Decimal::from(3));
+    assert!(q.is_nan() && r.is_nan());
+    assert!(cx.status().division_impossible());
+
+    cx.clear_status();
+    let (q, r) = cx.div_rem(&Decimal::from(1), &Decimal::zero());
+    assert!(q.is_infinite());
+    assert!(r.is_nan());
+    assert!(cx.status().division_by_zero());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_decimal_try_to_width() {
+    let wide: Decimal<14> = "1.2345678901234567890123456789012345678901"
+        .parse()
+        .unwrap();
+    let e = wide.try_to_width::<12>().unwrap_err();
+    assert!(e.status().inexact());
+    assert!(e.status().rounded());
+
+    let wide: Decimal<14> = "-1.5E+100".parse().unwrap();
+    let narrow = wide.try_to_width::<12>().unwrap();
+    assert_eq!(narrow.to_string(), "-1.5E+100");
+
+    // Trailing zeros that do not fit are dropped without loss of value.
+    let wide: Decimal<14> = "1.00000000000000000000000000000000000000000"
+        .parse()
+        .unwrap();
+    let narrow = wide.try_to_width::<12>().unwrap();
+    assert_eq!(narrow, Decimal::<12>::from(1));
+
+    let narrow: Decimal<12> = "1.23456789012345678901234567890123456".parse().unwrap();
+    let widened = narrow.try_to_width::<14>().unwrap();
+    assert_eq!(widened.to_string(), narrow.to_string());
+
+    for s in &["-0", "-0.00", "-Infinity", "NaN"] {
+        let d: Decimal<12> = s.parse().unwrap();
+        assert_eq!(d.try_to_width::<13>().unwrap().to_string(), *s);
+    }
+
+    let snan: Decimal<12> = "sNaN".parse().unwrap();
+    let e = snan.try_to_width::<13>().unwrap_err();
+    assert!(e.status().invalid_operation());
+}
+
+#[test]
+fn test_from_raw_parts() {
+    for s in &[
+        "0",
+        "-0.000",
+        "1.5E+100",
+        "-123456789012345678901234567890123456",
+        "1E-1000000034",
+        "-Infinity",
+        "NaN123",
+        "sNaN",
+    ] {
+        let d: Decimal<12> = s.parse().unwrap();
+        let (digits, exponent, bits, lsu) = d.to_raw_parts();
+        let r = Decimal::<12>::from_raw_parts(digits, exponent, bits, lsu).unwrap();
+        assert_eq!(r.to_string(), *s);
+    }
+
+    let mut lsu = [0; 12];
+    lsu[0] = 123;
+    lsu[5] = 999;
+    let d = Decimal::<12>::from_raw_parts(3, -2, 0, lsu).unwrap();
+    assert_eq!(d.to_string(), "1.23");
+    assert_eq!(d.to_raw_parts().3[5], 0);
+
+    for (digits, exponent, bits, msu, reason) in &[
+        (3, 0, 0x01, 123, "unknown or conflicting flags"),
+        (3, 0, 0x60, 123, "unknown or conflicting flags"),
+        (0, 0, 0, 0, "digit count out of range"),
+        (37, 0, 0, 0, "digit count out of range"),
+        (3, 0, 0, 1000, "invalid coefficient unit"),
+        (3, 0, 0, 12, "digit count does not match coefficient"),
+        (2, 0, 0, 123, "digit count does not match coefficient"),
+        (3, 999_999_998, 0, 123, "exponent out of range"),
+        (1, 0, 0x40, 1, "infinity with a coefficient"),
+        (3, 0, 0xc0, 123, "infinity with a coefficient"),
+    ] {
+        let mut lsu = [0; 12];
+        lsu[0] = *msu;
+        let e = Decimal::<12>::from_raw_parts(*digits, *exponent, *bits, lsu).unwrap_err();
+        assert_eq!(e.to_string(), format!("invalid decimal: {}", reason));
+    }
+}
+
+#[test]
+fn test_try_sum_product() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .max_exponent(5)
+        .build()
+        .unwrap();
+    let parse =
+        |ss: &[&str]| -> Vec<Decimal<12>> { ss.iter().map(|s| s.parse().unwrap()).collect() };
+
+    let xs = parse(&["1.5", "2.25", "-0.75"]);
+    assert_eq!(cx.try_sum(xs.iter()).unwrap().to_string(), "3.00");
+    let e = cx.try_product(xs.iter()).unwrap_err();
+    assert!(e.status().inexact());
+    assert!(cx.status().inexact());
+    let xs = parse(&["1.5", "2.5", "-0.75"]);
+    assert_eq!(cx.try_product(xs.iter()).unwrap().to_string(), "-2.8125");
+
+    cx.clear_status();
+    assert_eq!(cx.try_sum([].iter()).unwrap().to_string(), "0");
+    assert_eq!(cx.try_product([].iter()).unwrap().to_string(), "1");
+    assert!(!cx.status().any());
+
+    // Inexact.
+    let e = cx.try_sum(parse(&["1", "0.00001"]).iter()).unwrap_err();
+    assert!(e.status().inexact());
+    // Overflow.
+    let e = cx
+        .try_product(parse(&["1000", "1000", "1000"]).iter())
+        .unwrap_err();
+    assert!(e.status().overflow());
+    // Invalid.
+    let e = cx
+        .try_sum(parse(&["Infinity", "-Infinity"]).iter())
+        .unwrap_err();
+    assert!(e.status().invalid_operation());
+    assert!(cx.status().invalid_operation());
+
+    // Conditions raised before the reduction do not cause an error.
+    let xs = parse(&["1", "2"]);
+    assert_eq!(cx.try_sum(xs.iter()).unwrap().to_string(), "3");
+}
+
+#[test]
+fn test_hypot() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (x, y, expected) in &[
+        ("3", "4", "5"),
+        ("-3", "4", "5"),
+        ("30", "-40", "50"),
+        ("0", "0", "0"),
+        ("-0", "7.5", "7.5"),
+        ("1", "1", "1.41421356237309504880168872420969808"),
+        (
+            "1E+999999999",
+            "1E+999999999",
+            "1.41421356237309504880168872420969808E+999999999",
+        ),
+        ("3E-999999999", "4E-999999999", "5E-999999999"),
+        ("1", "1E-50", "1.000000000000000000"),
+        ("Infinity", "NaN", "Infinity"),
+        ("sNaN", "-Infinity", "Infinity"),
+        ("NaN", "1", "NaN"),
+        ("2", "NaN", "NaN"),
+    ] {
+        let x: Decimal<12> = x.parse().unwrap();
+        let y: Decimal<12> = y.parse().unwrap();
+        assert_eq!(cx.hypot(&x, &y).to_string(), *expected, "{} {}", x, y);
+        assert_eq!(cx.hypot(&y, &x).to_string(), *expected, "{} {}", y, x);
+    }
+
+    cx.clear_status();
+    let r = cx.hypot(&Decimal::from(3), &Decimal::from(4));
+    assert_eq!(r.to_string(), "5");
+    assert!(!cx.status().any());
+
+    let tiny: Decimal<12> = "1E-50".parse().unwrap();
+    cx.hypot(&Decimal::from(1), &tiny);
+    assert!(cx.status().inexact());
+
+    cx.clear_status();
+    let snan: Decimal<12> = "sNaN".parse().unwrap();
+    assert!(cx.hypot(&snan, &Decimal::from(1)).is_nan());
+    assert!(cx.status().invalid_operation());
+
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .max_exponent(10)
+        .build()
+        .unwrap();
+    let big: Decimal<12> = "9E+10".parse().unwrap();
+    assert!(cx.hypot(&big, &big).is_infinite());
+    assert!(cx.status().overflow());
+}
+
+#[test]
+fn test_with_exponent() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (n, exp, expected, inexact) in &[
+        ("1.005", -2, "1.01", true),
+        ("1.004", -2, "1.00", true),
+        ("12", -2, "12.00", false),
+        ("1.50", -1, "1.5", false),
+        ("-2.345", -2, "-2.35", true),
+        ("1234.5", 2, "1.2E+3", true),
+        ("Infinity", -2, "NaN", false),
+    ] {
+        cx.clear_status();
+        let n: Decimal<12> = n.parse().unwrap();
+        let r = cx.with_exponent(&n, *exp);
+        assert_eq!(r.to_string(), *expected, "{} {}", n, exp);
+        assert_eq!(cx.status().inexact(), *inexact, "{} {}", n, exp);
+    }
+
+    let n: Decimal<12> = "3.14159".parse().unwrap();
+    let r = cx.with_exponent(&n, -2);
+    assert_eq!(r.exponent(), -2);
+    assert_eq!(n.to_string(), "3.14159");
+
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .build()
+        .unwrap();
+    let n: Decimal<12> = "12345".parse().unwrap();
+    assert!(cx.with_exponent(&n, -2).is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_fixed_width_context_defaults() {
+    let mut cx = Context::<Decimal32>::default();
+    assert_eq!(cx.rounding(), Rounding::HalfEven);
+    assert_eq!(cx.parse("1.0000005").unwrap().to_string(), "1.000000");
+    assert_eq!(cx.parse("1E+96").unwrap().to_string(), "1.000000E+96");
+    assert_eq!(cx.parse("1E+97").unwrap().to_string(), "Infinity");
+
+    let mut cx = Context::<Decimal64>::default();
+    assert_eq!(cx.rounding(), Rounding::HalfEven);
+    assert_eq!(
+        cx.parse("1.0000000000000005").unwrap().to_string(),
+        "1.000000000000000"
+    );
+    assert!(cx.parse("1E+385").unwrap().is_infinite());
+
+    let mut cx = Context::<Decimal128>::default();
+    assert_eq!(cx.rounding(), Rounding::HalfEven);
+    assert_eq!(
+        cx.parse("1.0000000000000000000000000000000005")
+            .unwrap()
+            .to_string(),
+        "1.000000000000000000000000000000000"
+    );
+    assert!(cx.parse("1E+6145").unwrap().is_infinite());
+}
+
+#[test]
+fn test_is_canonical() {
+    for s in &["0", "-1.5", "1E+96", "Infinity", "NaN123"] {
+        let d: Decimal32 = s.parse().unwrap();
+        assert!(d.is_canonical(), "{}", s);
+        let d: Decimal<12> = s.parse().unwrap();
+        assert!(d.is_canonical(), "{}", s);
+    }
+
+    // 0x3FF is a redundant encoding of the declet 999.
+    let bits = u32::from_be_bytes(Decimal32::ONE.to_be_bytes()) | 0x3FF;
+    let d = Decimal32::from_be_bytes(bits.to_be_bytes());
+    assert!(!d.is_canonical());
+    assert_eq!(d.to_string(), "999");
+
+    // Infinities must have a zero coefficient continuation.
+    let d = Decimal32::from_be_bytes([0x78, 0, 0, 1]);
+    assert_eq!(d.to_string(), "Infinity");
+    assert!(!d.is_canonical());
+
+    let bits = u64::from_be_bytes(Decimal64::ONE.to_be_bytes()) | 0x3FF;
+    assert!(!Decimal64::from_be_bytes(bits.to_be_bytes()).is_canonical());
+    assert!(Decimal64::ONE.is_canonical());
+}
+
+#[test]
+fn test_quantize_to_places() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (n, places, expected) in &[
+        ("1.005", 2, "1.01"),
+        ("1.004", 2, "1.00"),
+        ("12", 2, "12.00"),
+        ("-0.5", 0, "-1"),
+        ("1234.5", -2, "1.2E+3"),
+        ("0.000", 1, "0.0"),
+        ("Infinity", 2, "NaN"),
+    ] {
+        let mut n: Decimal<12> = n.parse().unwrap();
+        cx.quantize_to_places(&mut n, *places);
+        assert_eq!(n.to_string(), *expected, "{}", places);
+    }
+
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .build()
+        .unwrap();
+    let mut n: Decimal<12> = "12345".parse().unwrap();
+    cx.quantize_to_places(&mut n, 2);
+    assert!(n.is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_precision_and_scale() {
+    for (n, expected) in &[
+        ("1.50", (3, 2)),
+        ("-1.50", (3, 2)),
+        ("1E+3", (4, 0)),
+        ("123", (3, 0)),
+        ("0.001", (3, 3)),
+        ("0", (1, 0)),
+        ("0.00", (2, 2)),
+        ("12.345E-2", (5, 5)),
+        ("Infinity", (0, 0)),
+        ("NaN", (0, 0)),
+    ] {
+        let d: Decimal<12> = n.parse().unwrap();
+        assert_eq!(d.precision_and_scale(), *expected, "{}", n);
+        assert_eq!(d.scale(), expected.1, "{}", n);
+    }
+}
+
+#[test]
+fn test_gcd_lcm() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (a, b, gcd, lcm) in &[
+        ("12", "18", "6", "36"),
+        ("-12", "18", "6", "36"),
+        ("-12", "-18", "6", "36"),
+        ("7", "13", "1", "91"),
+        ("0", "5", "5", "0"),
+        ("0", "0", "0", "0"),
+        ("4.0", "6", "2", "12"),
+        ("1E+2", "15", "5", "300"),
+        ("1E+3", "1E+2", "100", "1000"),
+    ] {
+        let a: Decimal<12> = a.parse().unwrap();
+        let b: Decimal<12> = b.parse().unwrap();
+        assert_eq!(cx.gcd(&a, &b).to_string(), *gcd, "gcd({}, {})", a, b);
+        assert_eq!(cx.gcd(&b, &a).to_string(), *gcd, "gcd({}, {})", b, a);
+        assert_eq!(cx.lcm(&a, &b).to_string(), *lcm, "lcm({}, {})", a, b);
+    }
+    assert!(!cx.status().any());
+
+    for (a, b) in &[("1.5", "3"), ("3", "Infinity"), ("NaN", "3")] {
+        cx.clear_status();
+        let a: Decimal<12> = a.parse().unwrap();
+        let b: Decimal<12> = b.parse().unwrap();
+        assert!(cx.gcd(&a, &b).is_nan());
+        assert!(cx.status().invalid_operation());
+        cx.clear_status();
+        assert!(cx.lcm(&a, &b).is_nan());
+        assert!(cx.status().invalid_operation());
+    }
+}
+
+#[test]
+fn test_digit() {
+    for s in &[
+        "0",
+        "7",
+        "-1234567.89",
+        "1000",
+        "9.87654321012345678901E+30",
+        "NaN123",
+        "Infinity",
+    ] {
+        let d: Decimal<12> = s.parse().unwrap();
+        let digits = d.coefficient_digits();
+        for (i, digit) in digits.iter().enumerate() {
+            assert_eq!(
+                d.digit(u32::try_from(i).unwrap()),
+                Some(*digit),
+                "{} {}",
+                s,
+                i
+            );
+        }
+        assert_eq!(d.digit(d.digits()), None);
+        assert_eq!(d.digit(u32::MAX), None);
+    }
+
+    let d: Decimal<12> = "4012".parse().unwrap();
+    assert_eq!(d.digit(0), Some(4));
+    assert_eq!(d.digit(1), Some(0));
+    assert_eq!(d.digit(3), Some(2));
+    assert_eq!(d.digit(4), None);
+}
+
+#[test]
+fn test_rem_euclid() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (lhs, rhs, expected) in &[
+        ("7", "4", "3"),
+        ("-7", "4", "1"),
+        ("7", "-4", "3"),
+        ("-7", "-4", "1"),
+        ("-6", "3", "0"),
+        ("-0", "3", "0"),
+        ("-7.5", "2", "0.5"),
+        ("-0.25", "1", "0.75"),
+        ("-1", "Infinity", "Infinity"),
+    ] {
+        let mut lhs: Decimal<12> = lhs.parse().unwrap();
+        let rhs: Decimal<12> = rhs.parse().unwrap();
+        cx.rem_euclid(&mut lhs, &rhs);
+        assert_eq!(lhs.to_string(), *expected, "{}", rhs);
+    }
+    assert!(!cx.status().any());
+
+    let mut lhs = Decimal::<12>::from(1);
+    cx.rem_euclid(&mut lhs, &Decimal::zero());
+    assert!(lhs.is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_status_flags() {
+    let mut status = Status::default();
+    assert!(status.is_empty());
+    assert!(status.flags().is_empty());
+    assert_eq!(status.to_string(), "");
+
+    status.set_overflow();
+    assert!(!status.is_empty());
+    assert_eq!(status.flags(), ["overflow"]);
+    assert_eq!(status.to_string(), "overflow");
+
+    let mut cx = Context::<Decimal<12>>::default();
+    let mut n = Decimal::<12>::from(1);
+    cx.div(&mut n, &Decimal::from(3));
+    assert_eq!(cx.status().flags(), ["inexact", "rounded"]);
+    assert_eq!(cx.status().to_string(), "inexact, rounded");
+
+    let mut n = Decimal::<12>::from(1);
+    cx.div(&mut n, &Decimal::zero());
+    assert_eq!(
+        cx.status().to_string(),
+        "division_by_zero, inexact, rounded"
+    );
+}
+
+#[test]
+fn test_div_exact() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (lhs, rhs, expected) in &[
+        ("100", "8", Some("12.5")),
+        ("1", "4", Some("0.25")),
+        ("-6", "3", Some("-2")),
+        ("1.00", "2", Some("0.50")),
+        ("1", "3", None),
+        ("2", "7", None),
+        ("1", "0", None),
+        ("0", "0", None),
+        ("sNaN", "1", None),
+    ] {
+        let lhs: Decimal<12> = lhs.parse().unwrap();
+        let rhs: Decimal<12> = rhs.parse().unwrap();
+        let q = cx.div_exact(&lhs, &rhs).map(|q| q.to_string()).ok();
+        assert_eq!(q.as_deref(), *expected, "{} / {}", lhs, rhs);
+    }
+
+    cx.clear_status();
+    let err = cx
+        .div_exact(&Decimal::from(1), &Decimal::from(3))
+        .unwrap_err();
+    assert!(err.status().inexact());
+    assert!(cx.status().inexact());
+
+    let err = cx
+        .div_exact(&Decimal::from(1), &Decimal::zero())
+        .unwrap_err();
+    assert!(err.status().division_by_zero());
+    assert!(!err.status().inexact());
+}
+
+#[test]
+fn test_range() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let collect = |cx: &mut Context<Decimal<12>>, start: &str, end: &str, step: &str| {
+        cx.range(
+            start.parse().unwrap(),
+            end.parse().unwrap(),
+            step.parse().unwrap(),
+        )
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(collect(&mut cx, "1", "5", "1"), ["1", "2", "3", "4"]);
+    assert_eq!(
+        collect(&mut cx, "0", "1", "0.3"),
+        ["0", "0.3", "0.6", "0.9"]
+    );
+    assert_eq!(collect(&mut cx, "3", "0", "-1"), ["3", "2", "1"]);
+    assert!(collect(&mut cx, "5", "1", "1").is_empty());
+    assert!(collect(&mut cx, "1", "5", "-1").is_empty());
+    assert!(collect(&mut cx, "1", "1", "1").is_empty());
+    assert!(!cx.status().any());
+
+    for (start, end, step) in &[
+        ("1", "5", "0"),
+        ("1", "5", "Infinity"),
+        ("1", "5", "NaN"),
+        ("NaN", "5", "1"),
+        ("-Infinity", "5", "1"),
+        ("1", "NaN", "1"),
+        ("1", "Infinity", "1"),
+        ("1", "-Infinity", "-1"),
+    ] {
+        cx.clear_status();
+        assert!(collect(&mut cx, start, end, step).is_empty());
+        assert!(cx.status().invalid_operation());
+    }
+
+    // A step too small to change the value at the context's precision ends
+    // the iteration rather than looping forever.
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(3)
+        .build()
+        .unwrap();
+    assert_eq!(collect(&mut cx, "100", "200", "0.01"), ["100"]);
+}
+
+#[test]
+fn test_recip() {
+    for (n, expected) in &[
+        ("4", "0.25"),
+        ("-8", "-0.125"),
+        ("0.5", "2"),
+        ("3", "0.333333333333333333333333333333333333"),
+        ("0", "Infinity"),
+        ("-0", "-Infinity"),
+        ("Infinity", "0E-1000000034"),
+        ("-Infinity", "-0E-1000000034"),
+        ("NaN", "NaN"),
+    ] {
+        let d: Decimal<12> = n.parse().unwrap();
+        assert_eq!(d.recip().to_string(), *expected, "{}", n);
+    }
+}
+
+#[test]
+fn test_ln1p_expm1() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(30)
+        .max_exponent(999_999)
+        .min_exponent(-999_999)
+        .build()
+        .unwrap();
+    for (n, ln1p, expm1) in &[
+        (
+            "1E-20",
+            "9.99999999999999999995000000000E-21",
+            "1.00000000000000000000500000000E-20",
+        ),
+        (
+            "0.05",
+            "0.0487901641694320030653744042232",
+            "0.0512710963760240396975176363356",
+        ),
+        (
+            "-0.05",
+            "-0.0512932943875505334261961442547",
+            "-0.0487705754992859909085746802203",
+        ),
+        (
+            "-0.099",
+            "-0.104250021373799137659970607151",
+            "-0.0942572919764515038428199670372",
+        ),
+        (
+            "0.1",
+            "0.0953101798043248600439521232808",
+            "0.105170918075647624811707826490",
+        ),
+        (
+            "-0.5",
+            "-0.693147180559945309417232121458",
+            "-0.393469340287366576396200465009",
+        ),
+        (
+            "3",
+            "1.38629436111989061883446424292",
+            "19.0855369231876677409285296546",
+        ),
+        (
+            "1E-40",
+            "1.00000000000000000000000000000E-40",
+            "1.00000000000000000000000000000E-40",
+        ),
+        ("0", "0", "0"),
+        ("-0", "-0", "-0"),
+        ("Infinity", "Infinity", "Infinity"),
+        ("-Infinity", "NaN", "-1"),
+        ("-1", "-Infinity", "-0.632120558828557678404476229839"),
+        ("NaN", "NaN", "NaN"),
+    ] {
+        let n: Decimal<12> = n.parse().unwrap();
+        let mut r = n;
+        cx.ln1p(&mut r);
+        assert_eq!(r.to_string(), *ln1p, "ln1p({})", n);
+        let mut r = n;
+        cx.expm1(&mut r);
+        assert_eq!(r.to_string(), *expm1, "expm1({})", n);
+    }
+
+    cx.clear_status();
+    let mut n: Decimal<12> = "1E-20".parse().unwrap();
+    cx.ln1p(&mut n);
+    assert!(cx.status().inexact());
+
+    cx.clear_status();
+    let mut n: Decimal<12> = "-2".parse().unwrap();
+    cx.ln1p(&mut n);
+    assert!(n.is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_log2() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(30)
+        .max_exponent(999_999)
+        .min_exponent(-999_999)
+        .build()
+        .unwrap();
+    for (n, expected) in &[
+        ("1", "0"),
+        ("2", "1"),
+        ("1024", "10"),
+        ("0.5", "-1"),
+        ("0.125", "-3"),
+        ("2.000", "1"),
+        ("1267650600228229401496703205376", "100"),
+    ] {
+        cx.clear_status();
+        let mut r: Decimal<12> = n.parse().unwrap();
+        cx.log2(&mut r);
+        assert_eq!(r.to_string(), *expected, "{}", n);
+        assert!(!cx.status().any(), "{}", n);
+    }
+
+    for (n, expected) in &[
+        ("3", "1.58496250072115618145373894395"),
+        ("10", "3.32192809488736234787031942949"),
+        ("0.1", "-3.32192809488736234787031942949"),
+        ("1E+100", "332.192809488736234787031942949"),
+        ("1.5", "0.584962500721156181453738943948"),
+        ("0", "-Infinity"),
+        ("Infinity", "Infinity"),
+        ("NaN", "NaN"),
+    ] {
+        let mut r: Decimal<12> = n.parse().unwrap();
+        cx.log2(&mut r);
+        assert_eq!(r.to_string(), *expected, "{}", n);
+    }
+
+    cx.clear_status();
+    let mut r = Decimal::<12>::from(-8);
+    cx.log2(&mut r);
+    assert!(r.is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_log_base() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(30)
+        .max_exponent(999_999)
+        .min_exponent(-999_999)
+        .build()
+        .unwrap();
+    for (n, base, expected) in &[
+        ("1000", "10", "3"),
+        ("0.001", "10", "-3"),
+        ("81", "3", "4"),
+        ("8", "0.5", "-3"),
+        ("1", "7", "0"),
+        ("2", "10", "0.301029995663981195213738894724"),
+        ("100", "3", "4.19180654857876920859313504404"),
+        ("0.3", "7", "-0.618719628401358713013378394472"),
+        ("1E+50", "1.01", "11570.3946279380564951813539519"),
+        ("0", "10", "-Infinity"),
+        ("0", "0.5", "Infinity"),
+        ("Infinity", "10", "Infinity"),
+        ("NaN", "10", "NaN"),
+        ("10", "NaN", "NaN"),
+    ] {
+        let n: Decimal<12> = n.parse().unwrap();
+        let base: Decimal<12> = base.parse().unwrap();
+        assert_eq!(
+            cx.log_base(&n, &base).to_string(),
+            *expected,
+            "{} {}",
+            n,
+            base
+        );
+    }
+
+    for base in &["1", "0", "-2", "Infinity", "-Infinity"] {
+        cx.clear_status();
+        let base: Decimal<12> = base.parse().unwrap();
+        assert!(cx.log_base(&Decimal::from(10), &base).is_nan(), "{}", base);
+        assert!(cx.status().invalid_operation(), "{}", base);
+    }
+
+    cx.clear_status();
+    assert!(cx
+        .log_base(&Decimal::from(-10), &Decimal::from(10))
+        .is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_shift_rotate_by() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(9)
+        .build()
+        .unwrap();
+    for (n, amount, shifted, rotated) in &[
+        ("34", 8, "400000000", "400000003"),
+        ("12", 9, "0", "12"),
+        ("123456789", -2, "1234567", "891234567"),
+        ("123456789", 0, "123456789", "123456789"),
+        ("123456789", -9, "0", "123456789"),
+        ("Infinity", 1, "Infinity", "Infinity"),
+    ] {
+        let n: Decimal<12> = n.parse().unwrap();
+        let mut r = n;
+        cx.shift_by(&mut r, *amount);
+        assert_eq!(r.to_string(), *shifted, "shift {} {}", n, amount);
+        let mut r = n;
+        cx.rotate_by(&mut r, *amount);
+        assert_eq!(r.to_string(), *rotated, "rotate {} {}", n, amount);
+    }
+    assert!(!cx.status().any());
+
+    for amount in &[10, -10, i32::MAX, i32::MIN] {
+        cx.clear_status();
+        let mut r = Decimal::<12>::from(1);
+        cx.shift_by(&mut r, *amount);
+        assert!(r.is_nan());
+        assert!(cx.status().invalid_operation());
+        cx.clear_status();
+        let mut r = Decimal::<12>::from(1);
+        cx.rotate_by(&mut r, *amount);
+        assert!(r.is_nan());
+        assert!(cx.status().invalid_operation());
+    }
+}
+
+#[test]
+fn test_logical_operands() {
+    for s in &[
+        "0",
+        "1",
+        "1010",
+        "0011",
+        "111111111111111111111111111111111111",
+    ] {
+        let d = Decimal::<12>::from_logical_str(s).unwrap();
+        assert!(d.is_logical(), "{}", s);
+        assert_eq!(d, s.parse::<Decimal<12>>().unwrap(), "{}", s);
+        assert_eq!(d.exponent(), 0);
+    }
+    assert_eq!(
+        Decimal::<12>::from_logical_str("0011").unwrap().to_string(),
+        "11"
+    );
+    for s in &["", "102", "1.0", "-1", " 1", &"1".repeat(37)] {
+        assert!(Decimal::<12>::from_logical_str(s).is_err(), "{}", s);
+    }
+
+    for (s, reason) in &[
+        ("12", "digit other than 0 or 1"),
+        ("1E+1", "nonzero exponent"),
+        ("1.0", "nonzero exponent"),
+        ("-1", "negative sign"),
+        ("-0", "negative sign"),
+        ("Infinity", "special value"),
+        ("NaN", "special value"),
+    ] {
+        let d: Decimal<12> = s.parse().unwrap();
+        assert!(!d.is_logical(), "{}", s);
+        let mut cx = Context::<Decimal<12>>::default();
+        let err = cx.try_and(&d, &Decimal::from(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("invalid logical operand: {}", reason)
+        );
+        assert!(cx.try_or(&Decimal::from(1), &d).is_err());
+        assert!(cx.try_xor(&d, &d).is_err());
+        assert!(cx.try_invert(&d).is_err());
+        assert!(!cx.status().any());
+    }
+
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(6)
+        .build()
+        .unwrap();
+    let a = Decimal::<12>::from_logical_str("1100").unwrap();
+    let b = Decimal::<12>::from_logical_str("1010").unwrap();
+    assert_eq!(cx.try_and(&a, &b).unwrap().to_string(), "1000");
+    assert_eq!(cx.try_or(&a, &b).unwrap().to_string(), "1110");
+    assert_eq!(cx.try_xor(&a, &b).unwrap().to_string(), "110");
+    assert_eq!(cx.try_invert(&a).unwrap().to_string(), "110011");
+    let err: InvalidLogicalOperandError = cx.try_and(&a, &Decimal::from(2)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid logical operand: digit other than 0 or 1"
+    );
+}
+
+#[test]
+fn test_to_engineering_string() {
+    for (n, expected) in &[
+        ("1.23E+4", "12.3E+3"),
+        ("-1.23E+4", "-12.3E+3"),
+        ("123.45", "123.45"),
+        ("1E-7", "100E-9"),
+        ("0E+5", "0.0E+6"),
+        ("-0", "-0"),
+        ("Infinity", "Infinity"),
+        ("-Infinity", "-Infinity"),
+        ("NaN", "NaN"),
+        ("-sNaN12", "-sNaN12"),
+    ] {
+        let d: Decimal<12> = n.parse().unwrap();
+        assert_eq!(d.to_engineering_string(), *expected, "{}", n);
+        assert_eq!(d.to_engineering_string(), format!("{:#}", d), "{}", n);
+    }
+}
+
+#[test]
+fn test_try_from_float() {
+    for (f, expected) in &[
+        (0.1, "0.1"),
+        (-2.5, "-2.5"),
+        (0.0, "0"),
+        (-0.0, "-0"),
+        (1e300, "1E+300"),
+        (5e-324, "5E-324"),
+        (f64::MAX, "1.7976931348623157E+308"),
+        (-2.2250738585072014e-308, "-2.2250738585072014E-308"),
+        (123456789.0, "123456789"),
+    ] {
+        let d = Decimal::<12>::try_from(*f).unwrap();
+        assert_eq!(d.to_string(), *expected, "{}", f);
+    }
+    for (f, expected) in &[
+        (0.1f32, "0.1"),
+        (16777216.0, "16777216"),
+        (-1.5e-45, "-1E-45"),
+    ] {
+        let d = Decimal::<12>::try_from(*f).unwrap();
+        assert_eq!(d.to_string(), *expected, "{}", f);
+    }
+
+    for f in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(Decimal::<12>::try_from(*f), Err(TryFromFloatError));
+    }
+    assert_eq!(Decimal::<12>::try_from(f32::NAN), Err(TryFromFloatError));
+    assert_eq!(TryFromFloatError.to_string(), "float is not finite");
+}
+
+#[test]
+fn test_mul_add() {
+    for (x, a, b, expected) in &[
+        ("2", "3", "1", "7"),
+        ("-1.5", "4", "0.25", "-5.75"),
+        ("0.1", "0.1", "-0.01", "0.00"),
+        (
+            "1.00000000000000000000000000000000001",
+            "1.00000000000000000000000000000000001",
+            "-1",
+            "2.00000000000000000000000000000000001E-35",
+        ),
+        ("1E+999999", "10", "0", "Infinity"),
+        ("Infinity", "0", "1", "NaN"),
+        ("NaN", "1", "1", "NaN"),
+    ] {
+        let x: Decimal<12> = x.parse().unwrap();
+        let a: Decimal<12> = a.parse().unwrap();
+        let b: Decimal<12> = b.parse().unwrap();
+        assert_eq!(
+            x.mul_add(&a, &b).to_string(),
+            *expected,
+            "{} {} {}",
+            x,
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn test_isqrt() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (n, expected) in &[
+        ("0", "0"),
+        ("-0", "0"),
+        ("1", "1"),
+        ("24", "4"),
+        ("25", "5"),
+        ("26", "5"),
+        ("25.00", "5"),
+        ("1E+2", "10"),
+        ("99999999999999999999", "9999999999"),
+        ("18446744073709551616", "4294967296"),
+        ("99999999999999999999999999999999999", "316227766016837933"),
+        ("999999999999999999999999999999999999", "999999999999999999"),
+        ("1E+70", "100000000000000000000000000000000000"),
+        ("1E+72", "1E+36"),
+        ("4E+72", "2E+36"),
+        ("1E+100", "1E+50"),
+        ("1.21E+74", "1.1E+37"),
+        (
+            "123456789012345678901234567890123456E+36",
+            "351364182882014425311122238169988292",
+        ),
+    ] {
+        let n: Decimal<12> = n.parse().unwrap();
+        assert_eq!(cx.isqrt(&n).unwrap().to_string(), *expected, "{}", n);
+    }
+    assert!(!cx.status().any());
+
+    for n in &["-4", "2.5", "Infinity", "NaN"] {
+        cx.clear_status();
+        let n: Decimal<12> = n.parse().unwrap();
+        let err = cx.isqrt(&n).unwrap_err();
+        assert!(err.status().invalid_operation(), "{}", n);
+        assert!(cx.status().invalid_operation(), "{}", n);
+    }
+
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .build()
+        .unwrap();
+    let n: Decimal<12> = "1E+12".parse().unwrap();
+    assert_eq!(cx.isqrt(&n).unwrap().to_string(), "1.0000E+6");
+    let n: Decimal<12> = "123456789012".parse().unwrap();
+    let err = cx.isqrt(&n).unwrap_err();
+    assert!(err.status().inexact());
+    let n: Decimal<12> = "1E+100".parse().unwrap();
+    assert_eq!(cx.isqrt(&n).unwrap().to_string(), "1E+50");
+
+    // Roots that lie between two adjacent representable integers.
+    let mut cx = Context::<Decimal<12>>::default();
+    for n in &["2E+72", "1E+73", "123456789012345678901234567890123456E+40"] {
+        cx.clear_status();
+        let n: Decimal<12> = n.parse().unwrap();
+        assert!(cx.isqrt(&n).unwrap_err().status().inexact(), "{}", n);
+    }
+}
+
+#[test]
+fn test_nth_root() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(30)
+        .build()
+        .unwrap();
+    for (x, n, expected) in &[
+        ("2", 5, "1.14869835499703500679862694678"),
+        ("-100", 3, "-4.64158883361277889241007635092"),
+        ("1E+100", 7, "193069772888325.016700707479984"),
+        ("0.5", 4, "0.840896415253714543031125476233"),
+        ("12345.678", 10, "2.56537875920591942321345950171"),
+        ("32", 5, "2"),
+        ("-32", 5, "-2"),
+        ("0.0625", 4, "0.5"),
+        ("1E+12", 6, "1E+2"),
+        ("16", 2, "4"),
+        ("7.5", 1, "7.5"),
+        ("0", 4, "0"),
+        ("-0", 3, "-0"),
+        ("Infinity", 4, "Infinity"),
+        ("-Infinity", 3, "-Infinity"),
+        ("NaN", 3, "NaN"),
+    ] {
+        cx.clear_status();
+        let mut r: Decimal<12> = x.parse().unwrap();
+        cx.nth_root(&mut r, *n);
+        assert_eq!(r.to_string(), *expected, "{} {}", x, n);
+        assert!(!cx.status().invalid_operation(), "{} {}", x, n);
+    }
+
+    for (x, n) in &[("-16", 4), ("-Infinity", 2), ("8", 0), ("8", 1_000_000)] {
+        cx.clear_status();
+        let mut r: Decimal<12> = x.parse().unwrap();
+        cx.nth_root(&mut r, *n);
+        assert!(r.is_nan(), "{} {}", x, n);
+        assert!(cx.status().invalid_operation(), "{} {}", x, n);
+    }
+}
+
+#[test]
+fn test_clamp_to_precision() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .build()
+        .unwrap();
+    for (n, expected, rounded, inexact) in &[
+        ("1.2345", "1.2345", false, false),
+        ("1.23456", "1.2346", true, true),
+        ("123456789", "1.2346E+8", true, true),
+        ("1.000000", "1.0000", true, false),
+        ("-0.000012345", "-0.000012345", false, false),
+        ("Infinity", "Infinity", false, false),
+    ] {
+        cx.clear_status();
+        let n: Decimal<12> = n.parse().unwrap();
+        let (r, was_rounded) = cx.clamp_to_precision(&n);
+        assert_eq!(r.to_string(), *expected, "{}", n);
+        assert_eq!(was_rounded, *rounded, "{}", n);
+        assert_eq!(cx.status().inexact(), *inexact, "{}", n);
+    }
+
+    // Previously raised conditions do not affect the result.
+    cx.clear_status();
+    let mut status = Status::default();
+    status.set_rounded();
+    cx.set_status(status);
+    let (_, was_rounded) = cx.clamp_to_precision(&Decimal::from(1));
+    assert!(!was_rounded);
+    assert!(cx.status().rounded());
+}
+
+#[test]
+fn test_equals() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (a, b, expected) in &[
+        ("1", "1", true),
+        ("1", "1.0", true),
+        ("1.000", "1E0", true),
+        ("100", "1E+2", true),
+        ("0", "-0", true),
+        ("0E+5", "0.00", true),
+        ("1", "-1", false),
+        ("1", "2", false),
+        ("10", "1", false),
+        ("123456789", "123456788", false),
+        ("1.5", "15E-1", true),
+        ("1.5", "1.6", false),
+        ("Infinity", "Infinity", true),
+        ("-Infinity", "Infinity", false),
+        ("Infinity", "1E+999999999", false),
+        ("0", "1E-999999999", false),
+        ("NaN", "NaN", false),
+        ("NaN", "1", false),
+    ] {
+        let a: Decimal<12> = a.parse().unwrap();
+        let b: Decimal<12> = b.parse().unwrap();
+        assert_eq!(cx.equals(&a, &b), *expected, "{} {}", a, b);
+        assert_eq!(cx.equals(&b, &a), *expected, "{} {}", b, a);
+        assert_eq!(
+            cx.partial_cmp(&a, &b) == Some(Ordering::Equal),
+            *expected,
+            "{} {}",
+            a,
+            b
+        );
+        assert_eq!(a == b, *expected, "{} {}", a, b);
+    }
+    assert!(!cx.status().any());
+
+    let snan: Decimal<12> = "sNaN".parse().unwrap();
+    assert!(!cx.equals(&snan, &Decimal::from(1)));
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_widen() {
+    for s in &[
+        "0",
+        "-0",
+        "1.5",
+        "-123456789012345678901234567890123456",
+        "1E-1000000034",
+        "9.99999999999999999999999999999999999E+999999999",
+        "Infinity",
+        "-Infinity",
+        "NaN123",
+        "-sNaN",
+    ] {
+        let d: Decimal<12> = s.parse().unwrap();
+        let wide = d.widen::<20>();
+        assert_eq!(wide.to_string(), *s, "{}", s);
+        assert_eq!(wide.to_raw_parts().2, d.to_raw_parts().2, "{}", s);
+        let same = d.widen::<12>();
+        assert_eq!(same.to_raw_parts(), d.to_raw_parts(), "{}", s);
+        if !d.is_nan() {
+            let mut cx = Context::<Decimal<20>>::default();
+            assert_eq!(wide, cx.to_width(d), "{}", s);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot widen Decimal<13> to the narrower Decimal<12>")]
+fn test_widen_narrower() {
+    Decimal::<13>::from(1).widen::<12>();
+}
+
+#[test]
+fn test_round_with() {
+    let mut cx = Context::<Decimal<12>>::default();
+    cx.set_precision(5).unwrap();
+    cx.set_rounding(Rounding::HalfEven);
+    for (rounding, input, expected) in &[
+        (Rounding::Down, "1.234567", "1.2345"),
+        (Rounding::Up, "1.234561", "1.2346"),
+        (Rounding::Floor, "-1.234561", "-1.2346"),
+        (Rounding::Ceiling, "-1.234569", "-1.2345"),
+        (Rounding::HalfUp, "1.234550", "1.2346"),
+        (Rounding::HalfDown, "1.234550", "1.2345"),
+        (Rounding::Up, "1.2", "1.2"),
+    ] {
+        let n: Decimal<12> = input.parse().unwrap();
+        let r = cx.round_with(*rounding, &n);
+        assert_eq!(r.to_string(), *expected, "{:?} {}", rounding, input);
+        assert_eq!(cx.rounding(), Rounding::HalfEven);
+    }
+}
+
+#[test]
+fn test_signed_zero() {
+    for (input, positive, negative) in &[
+        ("0", true, false),
+        ("0E-10", true, false),
+        ("0E+10", true, false),
+        ("-0", false, true),
+        ("-0.000", false, true),
+        ("1", false, false),
+        ("-1", false, false),
+        ("Infinity", false, false),
+        ("-Infinity", false, false),
+        ("NaN", false, false),
+        ("-NaN", false, false),
+    ] {
+        let n: Decimal<12> = input.parse().unwrap();
+        assert_eq!(n.is_positive_zero(), *positive, "{}", input);
+        assert_eq!(n.is_negative_zero(), *negative, "{}", input);
+    }
+}
+
+#[test]
+fn test_ieee_remainder() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (lhs, rhs, expected) in &[
+        ("10", "3", "1"),
+        ("11", "3", "-1"),
+        ("-11", "3", "1"),
+        ("10", "4", "2"),
+        ("14", "4", "-2"),
+        ("10.6", "1", "-0.4"),
+        ("2.1", "-3", "-0.9"),
+        ("-0", "3", "-0"),
+        ("1", "0", "NaN"),
+        ("Infinity", "3", "NaN"),
+        ("3", "Infinity", "3"),
+    ] {
+        let lhs: Decimal<12> = lhs.parse().unwrap();
+        let rhs: Decimal<12> = rhs.parse().unwrap();
+        let r = cx.ieee_remainder(&lhs, &rhs);
+        assert_eq!(r.to_string(), *expected, "{} {}", lhs, rhs);
+        let mut in_place = lhs;
+        cx.rem_near(&mut in_place, &rhs);
+        assert_eq!(r.to_string(), in_place.to_string());
+    }
+}
+
+#[test]
+fn test_fused_mul_add() {
+    let mut cx = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .max_exponent(999_999)
+        .min_exponent(-999_999)
+        .build()
+        .unwrap();
+    for (x, y, z, expected) in &[
+        ("2", "3", "4", "10"),
+        ("1.0001", "1.0001", "-1.0002", "1E-8"),
+        ("-1.5", "2", "0.25", "-2.75"),
+        ("Infinity", "0", "1", "NaN"),
+        ("1", "1", "NaN", "NaN"),
+    ] {
+        let x: Decimal<12> = x.parse().unwrap();
+        let y: Decimal<12> = y.parse().unwrap();
+        let z: Decimal<12> = z.parse().unwrap();
+        let original = x;
+        let r = cx.fused_mul_add(&x, &y, &z);
+        assert_eq!(r.to_string(), *expected, "{} {} {}", x, y, z);
+        assert_eq!(x.to_raw_parts(), original.to_raw_parts());
+    }
+
+    let mut cx = Context::<Decimal<12>>::default();
+    let one = Decimal::<12>::from(1);
+    assert!(cx.fused_mul_add(&one, &one, &one).is_nan());
+    assert!(cx.status().invalid_context());
+}
+
+#[test]
+fn test_checked_pow_i32() {
+    for (base, exp, expected) in &[
+        ("2", 10, Some("1024")),
+        ("1.05", 3, Some("1.157625")),
+        ("-3", 3, Some("-27")),
+        ("2", -2, Some("0.25")),
+        ("3", -1, None),
+        ("0", 0, Some("1")),
+        ("Infinity", 0, Some("1")),
+        ("0", 3, Some("0")),
+        ("0", -1, None),
+        ("Infinity", 2, Some("Infinity")),
+        ("NaN", 0, None),
+        ("sNaN", 2, None),
+        ("10", 35, Some("100000000000000000000000000000000000")),
+        ("10", 36, Some("1.00000000000000000000000000000000000E+36")),
+        ("11", 35, None),
+        ("1E+500000000", 2, None),
+        ("1E-500000000", 2, Some("1E-1000000000")),
+        ("1E-600000000", 2, None),
+    ] {
+        let base: Decimal<12> = base.parse().unwrap();
+        let r = base.checked_pow_i32(*exp).map(|r| r.to_string());
+        assert_eq!(r.as_deref(), *expected, "{}^{}", base, exp);
+    }
+}
+
+#[test]
+fn test_compensated_sum() {
+    const N: usize = 12;
+    let mut cx = Context::<Decimal<N>>::default();
+    assert_eq!(cx.compensated_sum([].iter()), Decimal::<N>::zero());
+    let xs: Vec<Decimal<N>> = (1..=20).map(Decimal::<N>::from).collect();
+    assert_eq!(cx.compensated_sum(xs.iter()), Decimal::<N>::from(210));
+    let xs: Vec<Decimal<N>> = ["1.5", "-2.25", "1E-10", "3"]
+        .iter()
+        .map(|s| cx.parse(*s).unwrap())
+        .collect();
+    assert_eq!(
+        cx.compensated_sum(xs.iter()).to_string(),
+        cx.sum(xs.iter()).to_string()
+    );
+    assert!(!cx.status().any());
+
+    let xs = vec![cx.parse("1.2345678").unwrap(); 10_000];
+    let exact = cx.sum(xs.iter());
+
+    let mut cx = Context::<Decimal<N>>::default();
+    cx.set_precision(8).unwrap();
+    let mut naive = cx.sum(xs.iter());
+    let compensated = cx.compensated_sum(xs.iter());
+    assert_eq!(compensated.to_string(), "12345.678");
+
+    let mut cx = Context::<Decimal<N>>::default();
+    cx.sub(&mut naive, &exact);
+    cx.abs(&mut naive);
+    assert!(naive > cx.parse("0.001").unwrap());
+
+    // Each addition of a small value to a large total loses digits that
+    // compensation recovers.
+    let mut cx = Context::<Decimal<N>>::default();
+    cx.set_precision(5).unwrap();
+    let mut xs = vec![cx.parse("10000").unwrap()];
+    xs.extend(vec![cx.parse("0.4").unwrap(); 10]);
+    assert_eq!(cx.sum(xs.iter()).to_string(), "10000");
+    assert_eq!(cx.compensated_sum(xs.iter()).to_string(), "10004");
+    xs.reverse();
+    assert_eq!(cx.sum(xs.iter()).to_string(), "10004");
+    assert_eq!(cx.compensated_sum(xs.iter()).to_string(), "10004");
+
+    // The small values are recovered even when they are absorbed by, and
+    // then outlast, a much larger value.
+    let mut cx = Context::<Decimal<N>>::default();
+    let xs: Vec<Decimal<N>> = ["1", "1E+100", "1", "-1E+100"]
+        .iter()
+        .map(|s| cx.parse(*s).unwrap())
+        .collect();
+    assert_eq!(cx.sum(xs.iter()).to_string(), "0E+65");
+    assert_eq!(cx.compensated_sum(xs.iter()).to_string(), "2");
+
+    // Compensation stops once the total is no longer finite.
+    let mut cx = Context::<Decimal<N>>::default();
+    let xs: Vec<Decimal<N>> = ["1", "Infinity", "2"]
+        .iter()
+        .map(|s| cx.parse(*s).unwrap())
+        .collect();
+    assert!(cx.compensated_sum(xs.iter()).is_infinite());
+    assert!(!cx.status().any());
+    let xs: Vec<Decimal<N>> = ["Infinity", "-Infinity"]
+        .iter()
+        .map(|s| cx.parse(*s).unwrap())
+        .collect();
+    assert!(cx.compensated_sum(xs.iter()).is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_to_i32_u32() {
+    for (input, i, u) in &[
+        ("0", Some(0), Some(0)),
+        ("-0", Some(0), Some(0)),
+        ("42", Some(42), Some(42)),
+        ("-42", Some(-42), None),
+        ("2147483647", Some(i32::MAX), Some(2147483647)),
+        ("-2147483648", Some(i32::MIN), None),
+        ("2147483648", None, Some(2147483648)),
+        ("4294967295", None, Some(u32::MAX)),
+        ("4294967296", None, None),
+        ("1.5", None, None),
+        ("1.0", None, None),
+        ("1E+1", None, None),
+        ("Infinity", None, None),
+        ("NaN", None, None),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        assert_eq!(d.to_i32().ok(), *i, "{}", input);
+        assert_eq!(d.to_u32().ok(), *u, "{}", input);
+        assert_eq!(i32::try_from(d).ok(), *i, "{}", input);
+        assert_eq!(u32::try_from(d).ok(), *u, "{}", input);
+    }
+}
+
+#[test]
+fn test_is_power_of_ten() {
+    for (input, expected) in &[
+        ("1", true),
+        ("10", true),
+        ("100.00", true),
+        ("1E+2", true),
+        ("0.01", true),
+        ("1.000E-999999999", true),
+        ("1E-1000000034", true),
+        ("-10", false),
+        ("-1", false),
+        ("2", false),
+        ("11", false),
+        ("101", false),
+        ("0.011", false),
+        ("0", false),
+        ("-0", false),
+        ("0E+10", false),
+        ("Infinity", false),
+        ("NaN", false),
+        ("NaN1", false),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        assert_eq!(d.is_power_of_ten(), *expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_bytes() {
+    use ParseDecimalErrorKind::*;
+
+    let mut cx = Context::<Decimal<12>>::default();
+    let long = format!("1{}", "0".repeat(99));
+    for s in &[
+        "0",
+        "-1.50",
+        "1E+3",
+        "-Infinity",
+        "sNaN12",
+        "123456789012345678901234567890123456",
+        "0.00000000000000000000000000000000000000000000000000000000001",
+        long.as_str(),
+    ] {
+        let expected = cx.parse(*s).unwrap();
+        let d = cx.parse_bytes(s.as_bytes()).unwrap();
+        assert_eq!(d.to_string(), expected.to_string(), "{}", s);
+    }
+
+    for (b, kind, position) in &[
+        (&b""[..], Empty, None),
+        (b"1.2.3", MultipleDecimalPoints, Some(3)),
+        (b"1\x002", UnexpectedCharacter, Some(1)),
+        (b"\0", UnexpectedCharacter, Some(0)),
+        (b"1\xff", UnexpectedCharacter, Some(1)),
+        (b"\xc3\xa9", UnexpectedCharacter, Some(0)),
+    ] {
+        let e = cx.parse_bytes(b).unwrap_err();
+        assert_eq!(e.kind(), *kind, "{:?}", b);
+        assert_eq!(e.position(), *position, "{:?}", b);
+    }
+    let mut long = vec![b'1'; 100];
+    long.push(b'x');
+    let e = cx.parse_bytes(&long).unwrap_err();
+    assert_eq!(e.kind(), UnexpectedCharacter);
+    assert_eq!(e.position(), Some(100));
+}
+
+#[test]
+fn test_parse_rust_literal() {
+    use ParseDecimalErrorKind::*;
+
+    let mut cx = Context::<Decimal<12>>::default();
+    for (s, expected) in &[
+        ("1_000.5", "1000.5"),
+        ("1_000_000", "1000000"),
+        ("-1__0", "-10"),
+        ("+0.000_1", "0.0001"),
+        ("1.5e1_0", "1.5E+10"),
+        ("1_2E-3", "0.012"),
+        ("123", "123"),
+        ("-Infinity", "-Infinity"),
+    ] {
+        let d = cx.parse_rust_literal(s).unwrap();
+        assert_eq!(d.to_string(), *expected, "{}", s);
+    }
+
+    for (s, kind, position) in &[
+        ("_1", UnexpectedCharacter, Some(0)),
+        ("1_", UnexpectedCharacter, Some(1)),
+        ("1__", UnexpectedCharacter, Some(1)),
+        ("-_1", UnexpectedCharacter, Some(1)),
+        ("1_.5", UnexpectedCharacter, Some(1)),
+        ("1._5", UnexpectedCharacter, Some(2)),
+        ("1_e5", UnexpectedCharacter, Some(1)),
+        ("1e_5", UnexpectedCharacter, Some(2)),
+        ("_", UnexpectedCharacter, Some(0)),
+        ("1_000.5.5", MultipleDecimalPoints, Some(7)),
+        ("1_000x", UnexpectedCharacter, Some(5)),
+        ("1_000f64", UnexpectedCharacter, Some(5)),
+        ("", Empty, None),
+    ] {
+        let e = cx.parse_rust_literal(s).unwrap_err();
+        assert_eq!(e.kind(), *kind, "{:?}", s);
+        assert_eq!(e.position(), *position, "{:?}", s);
+    }
+}
+
+#[test]
+fn test_max_min_of() {
+    let mut cx = Context::<Decimal<12>>::default();
+    assert_eq!(cx.max_of(&[]), None);
+    assert_eq!(cx.min_of(&[]), None);
+
+    for (values, max, min) in &[
+        (&["3"][..], "3", "3"),
+        (&["1", "-2", "3.5", "0"], "3.5", "-2"),
+        (&["NaN", "1", "2"], "2", "1"),
+        (&["1", "NaN", "-1"], "1", "-1"),
+        (&["NaN", "NaN"], "NaN", "NaN"),
+        (&["-Infinity", "5", "Infinity"], "Infinity", "-Infinity"),
+        (&["0", "-0"], "0", "-0"),
+        (&["1.0", "1.00", "1"], "1", "1.00"),
+    ] {
+        let values: Vec<Decimal<12>> = values.iter().map(|v| cx.parse(*v).unwrap()).collect();
+        assert_eq!(
+            cx.max_of(&values).unwrap().to_string(),
+            *max,
+            "{:?}",
+            values
+        );
+        assert_eq!(
+            cx.min_of(&values).unwrap().to_string(),
+            *min,
+            "{:?}",
+            values
+        );
+    }
+    assert!(!cx.status().invalid_operation());
+
+    let values: Vec<Decimal<12>> = ["1", "sNaN", "2"]
+        .iter()
+        .map(|v| cx.parse(*v).unwrap())
+        .collect();
+    assert_eq!(cx.max_of(&values).unwrap().to_string(), "2");
+    assert!(cx.status().invalid_operation());
+
+    cx.clear_status();
+    let values: Vec<Decimal<12>> = ["1", "2", "sNaN"]
+        .iter()
+        .map(|v| cx.parse(*v).unwrap())
+        .collect();
+    assert!(cx.min_of(&values).unwrap().is_nan());
+    assert!(cx.status().invalid_operation());
+}
+
+#[test]
+fn test_to_i128_scaled() {
+    for (input, scale, expected) in &[
+        ("1.5", 2, Some(150)),
+        ("-1.5", 2, Some(-150)),
+        ("1.50", 1, Some(15)),
+        ("1.55", 1, None),
+        ("1500", -2, Some(15)),
+        ("1550", -2, None),
+        ("1E+3", 0, Some(1000)),
+        ("0", 5, Some(0)),
+        ("-0.00", 0, Some(0)),
+        ("0.001", 2, None),
+        ("123", 0, Some(123)),
+        ("1", 35, Some(10i128.pow(35))),
+        ("1", 36, None),
+        ("-170141183460469231731687303715884105728", 0, None),
+        ("1", i32::MIN, None),
+        ("1", i32::MAX, None),
+        ("Infinity", 0, None),
+        ("NaN", 0, None),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        assert_eq!(
+            d.to_i128_scaled(*scale).ok(),
+            *expected,
+            "{} {}",
+            input,
+            scale
+        );
+    }
+
+    let d: Decimal<14> = "-170141183460469231731687303715884105728".parse().unwrap();
+    assert_eq!(d.to_i128_scaled(0).ok(), Some(i128::MIN));
+    let d: Decimal<14> = "17014118346046923173168730371588410572.7".parse().unwrap();
+    assert_eq!(d.to_i128_scaled(1).ok(), Some(i128::MAX));
+    let d: Decimal<14> = "17014118346046923173168730371588410572.8".parse().unwrap();
+    assert_eq!(d.to_i128_scaled(1).ok(), None);
+}
+
+#[test]
+fn test_with_precision() {
+    let mut cx = Context::<Decimal<12>>::default();
+    cx.set_precision(5).unwrap();
+    let third = cx
+        .with_precision(20, |cx| {
+            assert_eq!(cx.precision(), 20);
+            let mut n = Decimal::<12>::from(1);
+            cx.div(&mut n, &Decimal::from(3));
+            n
+        })
+        .unwrap();
+    assert_eq!(third.to_string(), "0.33333333333333333333");
+    assert_eq!(cx.precision(), 5);
+    assert!(cx.status().inexact());
+
+    // Invalid precisions are rejected without running the closure.
+    for precision in &[0, 37] {
+        let mut ran = false;
+        assert!(cx.with_precision(*precision, |_| ran = true).is_err());
+        assert!(!ran);
+        assert_eq!(cx.precision(), 5);
+    }
+
+    // The precision is restored even if the closure panics.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        cx.with_precision(30, |_| panic!("boom")).unwrap();
+    }));
+    assert!(result.is_err());
+    assert_eq!(cx.precision(), 5);
+}
+
+#[test]
+fn test_abs_diff() {
+    for (a, b, expected) in &[
+        ("3", "5", "2"),
+        ("5", "3", "2"),
+        ("-1.5", "2.25", "3.75"),
+        ("1.0", "1", "0.0"),
+        ("-0", "0", "0"),
+        ("Infinity", "1", "Infinity"),
+        ("1", "Infinity", "Infinity"),
+        ("Infinity", "Infinity", "NaN"),
+        ("-NaN", "1", "NaN"),
+        ("1", "sNaN", "NaN"),
+        ("1E+36", "1", "999999999999999999999999999999999999"),
+        ("1E+37", "1", "1.00000000000000000000000000000000000E+37"),
+    ] {
+        let a: Decimal<12> = a.parse().unwrap();
+        let b: Decimal<12> = b.parse().unwrap();
+        assert_eq!(a.abs_diff(&b).to_string(), *expected, "{} {}", a, b);
+    }
+}
+
+#[test]
+fn test_is_close() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (a, b, rel_tol, abs_tol, expected) in &[
+        ("1", "1", "0", "0", true),
+        ("1.0", "1.00", "0", "0", true),
+        ("1", "1.000000001", "1E-9", "0", true),
+        ("1", "1.00000001", "1E-9", "0", false),
+        ("1000", "1001", "0.001", "0", true),
+        ("1000", "1002", "0.001", "0", false),
+        ("-1000", "-1001", "0.001", "0", true),
+        ("0", "1E-10", "1E-9", "0", false),
+        ("0", "1E-10", "1E-9", "1E-10", true),
+        ("0", "-0", "0", "0", true),
+        ("Infinity", "Infinity", "0", "0", true),
+        ("-Infinity", "-Infinity", "0.5", "0", true),
+        ("Infinity", "-Infinity", "1", "1", false),
+        ("Infinity", "1E+999999999", "1", "1", false),
+        ("NaN", "NaN", "1", "1", false),
+        ("NaN", "1", "1", "1", false),
+        ("1", "sNaN", "1", "1", false),
+        ("1E+999999999", "-1E+999999999", "1", "0", false),
+        ("1E+999999999", "-1E+999999999", "2", "0", true),
+    ] {
+        let parse = |s: &str| s.parse::<Decimal<12>>().unwrap();
+        let close = cx.is_close(&parse(a), &parse(b), &parse(rel_tol), &parse(abs_tol));
+        assert_eq!(close, *expected, "{} {} {} {}", a, b, rel_tol, abs_tol);
+        let close = cx.is_close(&parse(b), &parse(a), &parse(rel_tol), &parse(abs_tol));
+        assert_eq!(close, *expected, "{} {} {} {}", b, a, rel_tol, abs_tol);
+    }
+    assert!(!cx.status().any());
+
+    let one = Decimal::<12>::from(1);
+    for (rel_tol, abs_tol) in &[("-1", "0"), ("0", "-1E-9"), ("NaN", "0"), ("0", "NaN")] {
+        cx.clear_status();
+        let rel_tol: Decimal<12> = rel_tol.parse().unwrap();
+        let abs_tol: Decimal<12> = abs_tol.parse().unwrap();
+        assert!(!cx.is_close(&one, &one, &rel_tol, &abs_tol));
+        assert!(cx.status().invalid_operation());
+    }
+}
+
+#[test]
+fn test_canonical_bytes() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let d = cx.parse("-12.345").unwrap();
+    assert_eq!(
+        d.to_canonical_bytes(),
+        vec![1, 0x80, 5, 5, 0x12, 0x34, 0x50]
+    );
+    let d = cx.parse("1E+999999999").unwrap();
+    assert_eq!(
+        d.to_canonical_bytes(),
+        vec![1, 0, 1, 0xfe, 0xa7, 0xd6, 0xb9, 0x07, 0x10]
+    );
+
+    for s in &[
+        "0",
+        "-0",
+        "0E-7",
+        "1",
+        "-12.345",
+        "1E+63",
+        "1E+64",
+        "1E-64",
+        "1E-65",
+        "123456789012345678901234567890123456",
+        "1.5E+999999999",
+        "-7E-1000000033",
+        "Infinity",
+        "-Infinity",
+        "NaN",
+        "-NaN123",
+        "sNaN456",
+    ] {
+        let d = cx.parse(*s).unwrap();
+        let bytes = d.to_canonical_bytes();
+
+        let same = Decimal::<12>::from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(same.to_string(), d.to_string(), "{}", s);
+        assert_eq!(same.to_canonical_bytes(), bytes, "{}", s);
+
+        let wide = Decimal::<20>::from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(wide.to_string(), d.to_string(), "{}", s);
+        assert_eq!(wide.to_canonical_bytes(), bytes, "{}", s);
+        let narrow = Decimal::<12>::from_canonical_bytes(&wide.to_canonical_bytes()).unwrap();
+        assert_eq!(narrow.to_string(), d.to_string(), "{}", s);
+    }
+
+    // A value that needs more capacity than the target provides.
+    let mut wide_cx = Context::<Decimal<20>>::default();
+    let wide = wide_cx
+        .parse("1234567890123456789012345678901234567890")
+        .unwrap();
+    assert_eq!(
+        Decimal::<12>::from_canonical_bytes(&wide.to_canonical_bytes()),
+        Err(InvalidCanonicalBytesError)
+    );
+
+    let bytes = cx.parse("-12.345").unwrap().to_canonical_bytes();
+    for len in 0..bytes.len() {
+        assert_eq!(
+            Decimal::<12>::from_canonical_bytes(&bytes[..len]),
+            Err(InvalidCanonicalBytesError),
+            "truncated to {} bytes",
+            len
+        );
+    }
+    let mut extended = bytes.clone();
+    extended.push(0);
+    for invalid in &[
+        extended,
+        // Unknown version.
+        vec![2, 0x80, 5, 5, 0x12, 0x34, 0x50],
+        // Non-minimal digit count.
+        vec![1, 0x80, 0x85, 0x00, 5, 0x12, 0x34, 0x50],
+        // Non-minimal exponent.
+        vec![1, 0x80, 5, 0x85, 0x00, 0x12, 0x34, 0x50],
+        // Digit count too large for a u32.
+        vec![1, 0x00, 0xff, 0xff, 0xff, 0xff, 0x1f, 0x00, 0x10],
+        // Exponent out of range.
+        vec![1, 0x00, 1, 0x80, 0x80, 0x80, 0x80, 0x10, 0x10],
+        // Non-decimal nibble.
+        vec![1, 0x80, 5, 5, 0x12, 0x3a, 0x50],
+        // Nonzero padding nibble.
+        vec![1, 0x80, 5, 5, 0x12, 0x34, 0x51],
+        // Leading zero.
+        vec![1, 0x80, 5, 5, 0x02, 0x34, 0x50],
+        // No digits.
+        vec![1, 0x00, 0, 0],
+        // Unknown flags.
+        vec![1, 0x01, 1, 0, 0x10],
+        // Conflicting flags.
+        vec![1, 0x60, 1, 0, 0x00],
+        // Infinity with a coefficient.
+        vec![1, 0x40, 1, 0, 0x10],
+    ] {
+        assert_eq!(
+            Decimal::<12>::from_canonical_bytes(invalid),
+            Err(InvalidCanonicalBytesError),
+            "{:?}",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_copy() {
+    let mut cx = Context::<Decimal<12>>::default();
+    cx.set_precision(3).unwrap();
+    for (input, plus) in &[
+        ("1.23456", "1.23"),
+        ("-0", "0"),
+        ("-0.00", "0.00"),
+        ("1E+999999999", "1E+999999999"),
+        ("-Infinity", "-Infinity"),
+        ("sNaN12", "NaN12"),
+        ("-NaN", "-NaN"),
+    ] {
+        cx.clear_status();
+        let n: Decimal<12> = input.parse().unwrap();
+        let copy = cx.copy(&n);
+        assert_eq!(copy.to_string(), *input);
+        assert_eq!(copy.to_raw_parts().0, n.to_raw_parts().0);
+        assert_eq!(copy.to_raw_parts().2, n.to_raw_parts().2);
+        assert!(!cx.status().any(), "{}", input);
+
+        let mut rounded = n;
+        cx.plus(&mut rounded);
+        assert_eq!(rounded.to_string(), *plus, "{}", input);
+    }
+}
+
+#[test]
+fn test_mul_pow10() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (input, e, expected, status) in &[
+        ("1.5", 3, "1.5E+3", ""),
+        ("1.5", -3, "0.0015", ""),
+        ("-12.345", 2, "-1234.5", ""),
+        ("1", 0, "1", ""),
+        ("0", 5, "0E+5", ""),
+        ("-0.00", 1, "-0.0", ""),
+        ("1E+999999998", 1, "1E+999999999", ""),
+        ("1E+999999998", 2, "Infinity", "inexact, overflow, rounded"),
+        (
+            "-12E+999999990",
+            9,
+            "-Infinity",
+            "inexact, overflow, rounded",
+        ),
+        ("1", i32::MAX, "Infinity", "inexact, overflow, rounded"),
+        ("0", i32::MAX, "0E+999999999", "clamped"),
+        ("-0", i32::MIN, "-0E-1000000034", "clamped"),
+        ("1E-999999999", -1, "1E-1000000000", "subnormal"),
+        ("1", -1000000034, "1E-1000000034", "subnormal"),
+        (
+            "12",
+            -1000000035,
+            "1E-1000000034",
+            "inexact, rounded, subnormal, underflow",
+        ),
+        (
+            "5",
+            -1000000036,
+            "0E-1000000034",
+            "inexact, clamped, rounded, subnormal, underflow",
+        ),
+        (
+            "-1",
+            i32::MIN,
+            "-0E-1000000034",
+            "inexact, clamped, rounded, subnormal, underflow",
+        ),
+        ("Infinity", 5, "Infinity", ""),
+        ("-sNaN3", 5, "-NaN3", "invalid_operation"),
+    ] {
+        let mut d: Decimal<12> = input.parse().unwrap();
+        cx.clear_status();
+        cx.mul_pow10(&mut d, *e);
+        assert_eq!(d.to_string(), *expected, "{} {}", input, e);
+        assert_eq!(cx.status().to_string(), *status, "{} {}", input, e);
+    }
+
+    // Within the range of `scaleb`, the result and status match it exactly,
+    // including in a context with a narrow range.
+    let narrow = ContextBuilder::<Decimal<12>>::new()
+        .precision(5)
+        .max_exponent(5)
+        .min_exponent(-5)
+        .build()
+        .unwrap();
+    for cx in &mut [cx, narrow] {
+        for input in &["123.456", "12345", "-1", "0", "-0.00", "sNaN"] {
+            let d: Decimal<12> = input.parse().unwrap();
+            for e in &[-20, -12, -7, -1, 0, 1, 2, 7, 20] {
+                let mut expected = d;
+                cx.clear_status();
+                cx.scaleb(&mut expected, &Decimal::from(*e));
+                let expected_status = cx.status();
+                let mut actual = d;
+                cx.clear_status();
+                cx.mul_pow10(&mut actual, *e);
+                assert_eq!(
+                    actual.to_raw_parts(),
+                    expected.to_raw_parts(),
+                    "{} {}",
+                    input,
+                    e
+                );
+                assert_eq!(cx.status(), expected_status, "{} {}", input, e);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_frexp10() {
+    let mut cx = Context::<Decimal<12>>::default();
+    for (input, mantissa, exp) in &[
+        ("1", "1", 0),
+        ("9.99", "9.99", 0),
+        ("10", "1.0", 1),
+        ("-1234.50", "-1.23450", 3),
+        ("0.00123", "1.23", -3),
+        ("1E+999999999", "1", 999999999),
+        (
+            "123456789012345678901234567890123456E+999999964",
+            "1.23456789012345678901234567890123456",
+            999999999,
+        ),
+        ("1E-1000000034", "1", -1000000034),
+        ("0", "0", 0),
+        ("-0.000", "-0", 0),
+        ("0E+10", "0", 0),
+        ("-Infinity", "-Infinity", 0),
+        ("NaN12", "NaN12", 0),
+        ("-sNaN", "-sNaN", 0),
+    ] {
+        let n: Decimal<12> = input.parse().unwrap();
+        cx.clear_status();
+        let (m, e) = cx.frexp10(&n);
+        assert!(!cx.status().any());
+        assert_eq!(m.to_string(), *mantissa, "{}", input);
+        assert_eq!(e, *exp, "{}", input);
+        if n.is_finite() {
+            let mut product = m;
+            cx.scaleb(&mut product, &Decimal::from(e));
+            assert_eq!(product, n, "{}", input);
+            if !n.is_zero() {
+                assert_eq!(product.to_string(), n.to_string(), "{}", input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_value_key() {
+    use std::collections::HashSet;
+
+    let mut cx = Context::<Decimal<12>>::default();
+    let groups: &[&[&str]] = &[
+        &["1", "1.0", "1.00", "100E-2", "0.1E+1"],
+        &["-1", "-1.000"],
+        &["0", "-0", "0.000", "0E+10", "-0E-1000000034"],
+        &["1234.5", "1.2345E+3", "1234.500000"],
+        &["Infinity", "Infinity"],
+        &["-Infinity"],
+        &["NaN", "-NaN", "NaN123", "sNaN", "-sNaN7"],
+        &["1E+999999999"],
+        &["1E-1000000034"],
+    ];
+    let mut keys = HashSet::new();
+    for group in groups {
+        let key = cx.parse(group[0]).unwrap().value_key();
+        for s in group.iter() {
+            let d = cx.parse(*s).unwrap();
+            assert_eq!(d.value_key(), key, "{} {}", group[0], s);
+        }
+        assert!(keys.insert(key), "{}", group[0]);
+    }
+
+    // Keys do not depend on the width.
+    let mut wide_cx = Context::<Decimal<20>>::default();
+    for s in &["1.50", "-0", "NaN", "-Infinity", "1E+999999999"] {
+        let narrow = cx.parse(*s).unwrap();
+        let wide = wide_cx.parse(*s).unwrap();
+        assert_eq!(narrow.value_key(), wide.value_key(), "{}", s);
+    }
+
+    // Deduplicating by key merges equal values.
+    let values: Vec<Decimal<12>> = ["1.0", "1.00", "2", "2.000", "-0", "0"]
+        .iter()
+        .map(|s| cx.parse(*s).unwrap())
+        .collect();
+    let distinct: HashSet<_> = values.iter().map(|d| d.value_key()).collect();
+    assert_eq!(distinct.len(), 3);
+}
+
+#[test]
+fn test_sum_at_scale() {
+    let mut cx = Context::<Decimal<12>>::default();
+    let parse = |values: &[&str]| -> Vec<Decimal<12>> {
+        values.iter().map(|s| s.parse().unwrap()).collect()
+    };
+
+    for (values, scale, expected) in &[
+        (&[][..], 2, "0.00"),
+        (&["1.5", "2.25"], 2, "3.75"),
+        (&["1", "2"], 2, "3.00"),
+        (&["1.50", "-0.500", "10"], 2, "11.00"),
+        (&["100", "2E+2"], -2, "3E+2"),
+        (&["0.10", "0.20"], 1, "0.3"),
+    ] {
+        let sum = cx.sum_at_scale(parse(values).iter(), *scale).unwrap();
+        assert_eq!(sum.to_string(), *expected, "{:?}", values);
+    }
+    // Discarding trailing zeros, as from `-0.500`, rounds but is exact.
+    assert!(cx.status().rounded());
+    assert!(!cx.status().inexact());
+    cx.clear_status();
+
+    // An element with finer precision than the scale.
+    let err = cx
+        .sum_at_scale(parse(&["1.00", "0.005"]).iter(), 2)
+        .unwrap_err();
+    assert!(err.status().inexact());
+    assert!(cx.status().inexact());
+
+    // Non-finite elements.
+    for value in &["Infinity", "NaN", "sNaN"] {
+        cx.clear_status();
+        let err = cx.sum_at_scale(parse(&["1", value]).iter(), 2).unwrap_err();
+        assert!(err.status().invalid_operation(), "{}", value);
+        assert!(cx.status().invalid_operation(), "{}", value);
+    }
+
+    // An element or sum that needs more digits than the precision.
+    cx.set_precision(5).unwrap();
+    cx.clear_status();
+    let err = cx.sum_at_scale(parse(&["1234"]).iter(), 2).unwrap_err();
+    assert!(err.status().invalid_operation());
+    let err = cx
+        .sum_at_scale(parse(&["999.99", "0.01"]).iter(), 2)
+        .unwrap_err();
+    assert!(err.status().rounded());
+    let err = cx
+        .sum_at_scale(parse(&["999.99", "0.02"]).iter(), 2)
+        .unwrap_err();
+    assert!(err.status().inexact());
+
+    // A scale that is out of range.
+    let err = cx.sum_at_scale(parse(&[]).iter(), i32::MIN).unwrap_err();
+    assert!(err.status().invalid_operation());
+}
+
+#[test]
+fn test_trailing_zeros() {
+    for (input, expected) in &[
+        ("1", 0),
+        ("10", 1),
+        ("1.500", 2),
+        ("1500", 2),
+        ("1.5E+3", 0),
+        ("-1000", 3),
+        ("10000", 4),
+        ("1000000", 6),
+        ("12000000000000000000000000000000000", 33),
+        ("100000000000000000000000000000000000", 35),
+        ("0", 0),
+        ("-0.000", 0),
+        ("Infinity", 0),
+        ("NaN100", 0),
+    ] {
+        let d: Decimal<12> = input.parse().unwrap();
+        assert_eq!(d.trailing_zeros(), *expected, "{}", input);
+        if d.is_finite() && !d.is_zero() {
+            assert_eq!(d.digits() - d.normalized().digits(), *expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proptest::prelude::*;
+
+use dec::{Context, Decimal};
+
+proptest! {
+    #[test]
+    fn test_proptest_round_trip(d in any::<Decimal<12>>()) {
+        prop_assert!(d.digits() as usize <= 12 * 3);
+        let mut cx = Context::<Decimal<12>>::default();
+        let parsed = cx.parse(d.to_string()).unwrap();
+        prop_assert_eq!(parsed.to_string(), d.to_string());
+        prop_assert!(!cx.status().any());
+    }
+
+    #[test]
+    fn test_proptest_add_commutes(a in any::<Decimal<12>>(), b in any::<Decimal<12>>()) {
+        // NaN results propagate the first NaN operand, so are not commutative.
+        prop_assume!(!a.is_nan() && !b.is_nan());
+        let mut cx = Context::<Decimal<12>>::default();
+        let mut x = a;
+        cx.add(&mut x, &b);
+        let mut y = b;
+        cx.add(&mut y, &a);
+        prop_assert_eq!(x.to_string(), y.to_string());
+    }
+}
@@ -13,7 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use serde_test::{assert_tokens, Token};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Configure, Token,
+};
 
 use dec::Context;
 
@@ -27,7 +29,7 @@ fn test_serde() {
         .unwrap();
 
     assert_tokens(
-        &d,
+        &d.compact(),
         &[
             Token::Struct {
                 name: "Decimal",
@@ -58,3 +60,236 @@ fn test_serde() {
         ],
     );
 }
+
+#[test]
+fn test_serde_human_readable() {
+    const N: usize = 12;
+    let mut cx = Context::<dec::Decimal<N>>::default();
+
+    for s in &["-1.23E+5", "0.000", "-0", "Infinity"] {
+        let d = cx.parse(*s).unwrap();
+        assert_tokens(&d.readable(), &[Token::Str(s)]);
+    }
+
+    // NaNs do not compare equal to themselves, so only check serialization.
+    for s in &["NaN", "-sNaN12"] {
+        let d = cx.parse(*s).unwrap();
+        assert_ser_tokens(&d.readable(), &[Token::Str(s)]);
+    }
+
+    assert_de_tokens_error::<serde_test::Readable<dec::Decimal<N>>>(
+        &[Token::Str("1.2.3")],
+        "invalid value: string \"1.2.3\", expected a decimal number with at most 36 digits",
+    );
+    assert_de_tokens_error::<serde_test::Readable<dec::Decimal<N>>>(
+        &[Token::Str("1234567890123456789012345678901234567890")],
+        "invalid value: string \"1234567890123456789012345678901234567890\", \
+         expected a decimal number with at most 36 digits",
+    );
+
+    // Numbers and the struct form written by earlier versions are accepted.
+    for (tokens, expected) in &[
+        (vec![Token::I64(-42)], "-42"),
+        (vec![Token::U64(u64::MAX)], "18446744073709551615"),
+        (vec![Token::F64(1.5)], "1.5"),
+        (vec![Token::F64(-0.1)], "-0.1"),
+    ] {
+        let expected = cx.parse(*expected).unwrap();
+        assert_de_tokens(&expected.readable(), tokens);
+    }
+    assert_de_tokens_error::<serde_test::Readable<dec::Decimal<N>>>(
+        &[Token::F64(f64::NAN)],
+        "invalid value: floating point `NaN`, expected a decimal number with at most 36 digits",
+    );
+
+    let d: dec::Decimal<N> = serde_json::from_str("1.5").unwrap();
+    assert_eq!(d.to_string(), "1.5");
+    let d: dec::Decimal<N> = serde_json::from_str(
+        r#"{"digits":5,"exponent":-3,"bits":128,"lsu":[345,12,0,0,0,0,0,0,0,0,0,0]}"#,
+    )
+    .unwrap();
+    assert_eq!(d.to_string(), "-12.345");
+    let err = serde_json::from_str::<dec::Decimal<N>>(
+        r#"{"digits":4,"exponent":0,"bits":0,"lsu":[345,0,0,0,0,0,0,0,0,0,0,0]}"#,
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid decimal: digit count does not match coefficient"));
+}
+
+#[test]
+fn test_serde_validation() {
+    const N: usize = 12;
+
+    fn raw_tokens(digits: u32, exponent: i32, bits: u8, lsu: [u16; N]) -> Vec<Token> {
+        let mut tokens = vec![
+            Token::Struct {
+                name: "Decimal",
+                len: 4,
+            },
+            Token::Str("digits"),
+            Token::U32(digits),
+            Token::Str("exponent"),
+            Token::I32(exponent),
+            Token::Str("bits"),
+            Token::U8(bits),
+            Token::Str("lsu"),
+            Token::Seq { len: Some(N) },
+        ];
+        tokens.extend(lsu.iter().map(|u| Token::U16(*u)));
+        tokens.extend(vec![Token::SeqEnd, Token::StructEnd]);
+        tokens
+    }
+
+    fn lsu(units: &[u16]) -> [u16; N] {
+        let mut lsu = [0; N];
+        lsu[..units.len()].copy_from_slice(units);
+        lsu
+    }
+
+    // Valid encodings, including garbage in unused units.
+    let mut cx = Context::<dec::Decimal<N>>::default();
+    for (tokens, expected) in &[
+        (raw_tokens(5, -3, 0x80, lsu(&[345, 12])), "-12.345"),
+        (raw_tokens(1, 0, 0, lsu(&[0, 999, 7])), "0"),
+        (raw_tokens(1, 0, 0x40, lsu(&[])), "Infinity"),
+    ] {
+        let expected = cx.parse(*expected).unwrap();
+        assert_de_tokens(&expected.compact(), tokens);
+    }
+
+    for (tokens, error) in &[
+        (
+            raw_tokens(1, 0, 0x01, lsu(&[1])),
+            "invalid decimal: unknown or conflicting flags",
+        ),
+        (
+            raw_tokens(1, 0, 0x60, lsu(&[1])),
+            "invalid decimal: unknown or conflicting flags",
+        ),
+        (
+            raw_tokens(0, 0, 0, lsu(&[])),
+            "invalid decimal: digit count out of range",
+        ),
+        (
+            raw_tokens(37, 0, 0, lsu(&[])),
+            "invalid decimal: digit count out of range",
+        ),
+        (
+            raw_tokens(4, 0, 0, lsu(&[1000, 1])),
+            "invalid decimal: invalid coefficient unit",
+        ),
+        (
+            raw_tokens(5, 0, 0, lsu(&[345, 1])),
+            "invalid decimal: digit count does not match coefficient",
+        ),
+        (
+            raw_tokens(5, 0, 0, lsu(&[345, 123])),
+            "invalid decimal: digit count does not match coefficient",
+        ),
+        (
+            raw_tokens(4, 0, 0, lsu(&[345, 0])),
+            "invalid decimal: digit count does not match coefficient",
+        ),
+        (
+            raw_tokens(1, 1_000_000_000, 0, lsu(&[1])),
+            "invalid decimal: exponent out of range",
+        ),
+        (
+            raw_tokens(1, -2_000_000_000, 0, lsu(&[1])),
+            "invalid decimal: exponent out of range",
+        ),
+    ] {
+        assert_de_tokens_error::<serde_test::Compact<dec::Decimal<N>>>(tokens, error);
+    }
+}
+
+#[test]
+fn test_serde_coefficient_scale() {
+    use dec::serde_coefficient_scale;
+
+    const N: usize = 12;
+
+    fn to_json(d: &dec::Decimal<N>) -> Result<String, serde_json::Error> {
+        let mut buf = Vec::new();
+        serde_coefficient_scale::serialize(d, &mut serde_json::Serializer::new(&mut buf))?;
+        Ok(String::from_utf8(buf).unwrap())
+    }
+
+    fn from_json(s: &str) -> Result<dec::Decimal<N>, serde_json::Error> {
+        serde_coefficient_scale::deserialize(&mut serde_json::Deserializer::from_str(s))
+    }
+
+    let mut cx = Context::<dec::Decimal<N>>::default();
+    for (s, json) in &[
+        ("0", r#"{"coeff":0,"scale":0}"#),
+        ("0.000", r#"{"coeff":0,"scale":3}"#),
+        ("-1.50", r#"{"coeff":-150,"scale":2}"#),
+        ("1E+3", r#"{"coeff":1,"scale":-3}"#),
+        (
+            "12345678901234567890.123456",
+            r#"{"coeff":12345678901234567890123456,"scale":6}"#,
+        ),
+        (
+            "-999999999999999999999999999999999999",
+            r#"{"coeff":-999999999999999999999999999999999999,"scale":0}"#,
+        ),
+        ("1E-1000000034", r#"{"coeff":1,"scale":1000000034}"#),
+        ("9E+999999999", r#"{"coeff":9,"scale":-999999999}"#),
+    ] {
+        let d = cx.parse(*s).unwrap();
+        assert_eq!(to_json(&d).unwrap(), *json);
+        let roundtrip = from_json(json).unwrap();
+        assert_eq!(roundtrip.to_string(), *s);
+    }
+
+    // The sign of negative zero is lost.
+    let d = cx.parse("-0.0").unwrap();
+    assert_eq!(to_json(&d).unwrap(), r#"{"coeff":0,"scale":1}"#);
+
+    for s in &["Infinity", "-Infinity", "NaN", "sNaN"] {
+        let d = cx.parse(*s).unwrap();
+        assert_eq!(
+            to_json(&d).unwrap_err().to_string(),
+            "cannot serialize an infinity or NaN as a coefficient and scale"
+        );
+    }
+    let mut cx = Context::<dec::Decimal<14>>::default();
+    let d = cx
+        .parse("1234567890123456789012345678901234567890")
+        .unwrap();
+    let mut buf = Vec::new();
+    let err = serde_coefficient_scale::serialize(&d, &mut serde_json::Serializer::new(&mut buf))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "decimal coefficient does not fit in an i128"
+    );
+
+    for (json, error) in &[
+        (
+            r#"{"coeff":1234567890123456789012345678901234567,"scale":0}"#,
+            "coefficient 1234567890123456789012345678901234567 has more than 36 digits",
+        ),
+        (
+            r#"{"coeff":1,"scale":-1000000000}"#,
+            "scale -1000000000 is out of range for coefficient 1",
+        ),
+        (
+            r#"{"coeff":12,"scale":-999999999}"#,
+            "scale -999999999 is out of range for coefficient 12",
+        ),
+        (
+            r#"{"coeff":1,"scale":2000000000}"#,
+            "scale 2000000000 is out of range for coefficient 1",
+        ),
+        (
+            r#"{"coeff":1,"scale":-2147483648}"#,
+            "scale -2147483648 is out of range for coefficient 1",
+        ),
+    ] {
+        let err = from_json(json).unwrap_err().to_string();
+        assert!(err.starts_with(error), "{}: {}", json, err);
+    }
+}
@@ -4,6 +4,7 @@
 // ICU license -- ICU 1.8.1 and later.
 
 #![allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! Bindings to libdecnumber.
 //!